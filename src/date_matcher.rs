@@ -1,10 +1,17 @@
 use super::TimeWarpError;
+use crate::date_parser::{DateParser, Locale, YearInference};
+use crate::date_time::DateTime;
 use crate::day_of_week::DayOfWeek;
 use crate::doy::{Doy, Tempus};
-use crate::error::parse_error;
+use crate::error::{error, parse_error, ErrorKind};
+use crate::holiday::Holiday;
 use crate::month_of_year::Month;
-use pest::iterators::Pairs;
+use crate::number_words;
+use crate::time_of_day::{DayPart, TimeOfDay};
+use pest::iterators::{Pair, Pairs};
 use pest::Parser;
+use std::cmp::Ordering;
+use std::ops::{Range, RangeInclusive};
 use std::str::FromStr;
 
 #[derive(Parser, Debug, Default)]
@@ -18,12 +25,85 @@ pub enum Direction {
     From,
 }
 
+/// The settings of a single parse: the reference date, the `Direction` and the options of
+/// the `DateParser`.
+#[derive(Copy, Clone)]
+pub(crate) struct Context<'p> {
+    pub(crate) today: Doy,
+    pub(crate) direction: Direction,
+    pub(crate) parser: &'p DateParser,
+    /// The start of a range while resolving its end: a bare weekday, month, holiday or a date
+    /// without a year is then the first occurrence on or after it.
+    pub(crate) after: Option<Doy>,
+}
+
+impl Context<'_> {
+    fn with_direction(self, direction: Direction) -> Self {
+        Self { direction, ..self }
+    }
+
+    /// Expands a two-digit year `yy`.
+    fn full_year(&self, yy: i32) -> i32 {
+        match self.parser.pivot {
+            Some(pivot) if yy < 100 => {
+                let year = pivot - pivot.rem_euclid(100) + yy;
+                if year < pivot {
+                    year + 100
+                } else {
+                    year
+                }
+            }
+            _ => correct_yyyy(yy, self.today.year),
+        }
+    }
+
+    /// Creates the date, rejecting impossible calendar dates in strict mode.
+    fn ymd(&self, yy: i32, mm: i32, dd: i32) -> Result<Doy, TimeWarpError> {
+        if self.parser.strict {
+            Doy::try_from_ymd(yy, mm, dd)
+        } else {
+            Ok(Doy::from_ymd(yy, mm, dd))
+        }
+    }
+
+    /// Creates the date without a given year, see `YearInference`. At the end of a range it is
+    /// the first occurrence on or after the start.
+    fn md(&self, mm: i32, dd: i32) -> Result<Doy, TimeWarpError> {
+        if let Some(after) = self.after {
+            let year = after.year + i32::from(Doy::from_ymd(after.year, mm, dd) < after);
+            return self.ymd(year, mm, dd);
+        }
+        let today = self.today;
+        let this_year = Doy::from_ymd(today.year, mm, dd);
+        let year = match self.parser.year_inference {
+            YearInference::CurrentYear => today.year,
+            YearInference::Past if this_year > today => today.year - 1,
+            YearInference::Future if this_year < today => today.year + 1,
+            YearInference::Past | YearInference::Future => today.year,
+            YearInference::Nearest => (today.year - 1..=today.year + 1)
+                .min_by_key(|&year| (Doy::from_ymd(year, mm, dd) - today).abs())
+                .unwrap_or(today.year),
+        };
+        self.ymd(year, mm, dd)
+    }
+
+    fn accepts(&self, locale: Locale) -> bool {
+        self.parser.locales.contains(&locale)
+    }
+}
+
 #[allow(clippy::unnecessary_wraps)]
 #[inline]
 fn ok_moment(d: Doy) -> Result<Tempus, TimeWarpError> {
     Ok(Tempus::Moment(d))
 }
 
+/// The byte span of `pair` within the input.
+fn span_of(pair: &Pair<'_, Rule>) -> Range<usize> {
+    let span = pair.as_span();
+    span.start()..span.end()
+}
+
 fn correct_yyyy(yy: i32, relative: i32) -> i32 {
     if yy > 100 {
         return yy;
@@ -39,93 +119,298 @@ fn correct_yyyy(yy: i32, relative: i32) -> i32 {
     }
 }
 
-fn yy_mm_dd(pairs: Pairs<'_, Rule>, today: Doy) -> Result<Tempus, TimeWarpError> {
-    let mut yy = today.year;
+fn yy_mm_dd(pairs: Pairs<'_, Rule>, cx: &Context) -> Result<Tempus, TimeWarpError> {
+    let mut yy = None;
     let mut mm = 0;
     let mut dd = 0;
     for pair in pairs {
         match pair.as_rule() {
-            Rule::yyyy => yy = correct_yyyy(i32::from_str(pair.as_str())?, today.year),
+            Rule::yyyy => yy = Some(cx.full_year(i32::from_str(pair.as_str())?)),
             Rule::mm => mm = i32::from_str(pair.as_str())?,
             Rule::dd => dd = i32::from_str(pair.as_str())?,
             _ => return parse_error(format!("No date. Found more than expected: {pair:?}")),
         };
     }
-    ok_moment(Doy::from_ymd(yy, mm, dd))
+    match yy {
+        Some(yy) => ok_moment(cx.ymd(yy, mm, dd)?),
+        None => ok_moment(cx.md(mm, dd)?),
+    }
+}
+
+/// Resolves `d/m/y` or `m/d/y`, depending on the accepted `Locale`s.
+fn date_slash(pairs: Pairs<'_, Rule>, cx: &Context) -> Result<Tempus, TimeWarpError> {
+    let mut yy = cx.today.year;
+    let mut parts = vec![];
+    for pair in pairs {
+        match pair.as_rule() {
+            Rule::yyyy => yy = cx.full_year(i32::from_str(pair.as_str())?),
+            Rule::dd => parts.push(i32::from_str(pair.as_str())?),
+            _ => return parse_error(format!("No date. Found more than expected: {pair:?}")),
+        };
+    }
+    let [first, second] = parts[..] else {
+        return parse_error("No date. Expected day and month");
+    };
+    let (mm, dd) = match (cx.accepts(Locale::EnUs), cx.accepts(Locale::EnGb)) {
+        (true, false) => (first, second),
+        (false, true) => (second, first),
+        (true, true) if first == second || second > 12 => (first, second),
+        (true, true) if first > 12 => (second, first),
+        (true, true) => {
+            return error(
+                ErrorKind::Ambiguous,
+                format!("Ambiguous date: {first}/{second} as m/d or d/m"),
+            )
+        }
+        (false, false) => return parse_error("No locale accepts dates as m/d/y or d/m/y"),
+    };
+    ok_moment(cx.ymd(yy, mm, dd)?)
 }
 
-fn date_long(pairs: Pairs<'_, Rule>, today: Doy) -> Result<Tempus, TimeWarpError> {
-    let mut yy = today.year;
+fn date_long(pairs: Pairs<'_, Rule>, cx: &Context) -> Result<Tempus, TimeWarpError> {
+    let mut yy = None;
     let mut mm = 0;
     let mut dd = 0;
     for pair in pairs {
         match pair.as_rule() {
-            Rule::yyyy => yy = correct_yyyy(i32::from_str(pair.as_str())?, today.year),
+            Rule::yyyy => yy = Some(cx.full_year(i32::from_str(pair.as_str())?)),
             Rule::month => {
                 mm = Month::from_month(pair.into_inner().next().unwrap().as_rule()) as i32;
             }
             Rule::dd => dd = i32::from_str(pair.as_str())?,
+            Rule::ordinal_word => dd = ordinal_of(&pair, 1..=31)?,
             _ => return parse_error(format!("No long-date. Found more than expected: {pair:?}")),
         };
     }
-    if yy < 100 {
-        yy += 2000;
+    match yy {
+        Some(yy) => ok_moment(cx.ymd(yy, mm, dd)?),
+        None => ok_moment(cx.md(mm, dd)?),
     }
-    ok_moment(Doy::from_ymd(yy, mm, dd))
 }
 
-fn date_week(pairs: Pairs<'_, Rule>, today: Doy) -> Result<Tempus, TimeWarpError> {
-    let mut yy = today.year;
+/// Resolves "März 2023", "Mar '23" or "2023-03" into the whole month.
+fn year_month(pairs: Pairs<'_, Rule>, cx: &Context) -> Result<Tempus, TimeWarpError> {
+    let mut yy = cx.today.year;
+    let mut mm = 0;
+    for pair in pairs {
+        match pair.as_rule() {
+            Rule::yyyy => yy = cx.full_year(i32::from_str(pair.as_str())?),
+            Rule::mm => mm = i32::from_str(pair.as_str())?,
+            Rule::month => {
+                mm = Month::from_month(pair.into_inner().next().unwrap().as_rule()) as i32;
+            }
+            _ => return parse_error(format!("No month. Found more than expected: {pair:?}")),
+        }
+    }
+    if !(1..=12).contains(&mm) {
+        return error(
+            ErrorKind::InvalidDate,
+            format!("Month out of range 1..12: '{mm}'"),
+        );
+    }
+    Ok(months_of(yy, mm, 1))
+}
+
+fn date_week(pairs: Pairs<'_, Rule>, cx: &Context) -> Result<Tempus, TimeWarpError> {
+    let mut yy = cx.today.year;
     let mut kw = 0;
     for pair in pairs {
         match pair.as_rule() {
-            Rule::yyyy => yy = correct_yyyy(i32::from_str(pair.as_str())?, today.year),
+            Rule::yyyy => yy = cx.full_year(i32::from_str(pair.as_str())?),
             Rule::kw => kw = i32::from_str(pair.as_str())?,
             _ => return parse_error(format!("No week-date. Found more than expected: {pair:?}")),
         }
     }
-    let start = Doy::from_week(yy, kw);
+    let start = if cx.parser.strict {
+        Doy::try_from_week(yy, kw)?
+    } else {
+        Doy::from_week(yy, kw)
+    };
     Ok(Tempus::Interval(start, start + 7))
 }
 
+/// Parses the `date` relative to `today`. Impossible calendar dates like "31.2.23" are
+/// rolled over ("3.3.23"), see `date_matcher_strict` to reject them.
+/// A shortcut for `DateParser::builder().today(today).build().parse_as(direction, date)`.
 pub fn date_matcher(
     today: Doy,
     direction: Direction,
     date: impl Into<String>,
 ) -> Result<Tempus, TimeWarpError> {
-    let text = date.into();
-    let mut amount = 0i32;
-    let mut forwards = direction == Direction::To;
-    for pair in DateMatcher::parse(Rule::date_matcher, &text)?
+    DateParser::builder()
+        .today(today)
+        .build()
+        .parse_as(direction, &date.into())
+}
+
+/// Parses the `date` relative to `today`, rejecting impossible calendar dates like "31.2.23"
+/// or "KW 23/53".
+pub fn date_matcher_strict(
+    today: Doy,
+    direction: Direction,
+    date: impl Into<String>,
+) -> Result<Tempus, TimeWarpError> {
+    DateParser::builder()
+        .today(today)
+        .strict(true)
+        .build()
+        .parse_as(direction, &date.into())
+}
+
+pub(crate) fn matcher(cx: &Context, text: &str) -> Result<Tempus, TimeWarpError> {
+    let pair = DateMatcher::parse(Rule::date_matcher, text)
+        .map_err(|e| TimeWarpError::from_pest(e, text))?
         .next()
         .unwrap()
         .into_inner()
-    {
+        .next()
+        .unwrap();
+    let span = span_of(&pair);
+    match pair.as_rule() {
+        Rule::range => range(pair.into_inner(), cx),
+        Rule::point => point(pair.into_inner(), cx),
+        _ => parse_error(format!("date_matcher :: {pair:?}")),
+    }
+    .map_err(|e| e.at(span))
+}
+
+/// Resolves "from X to Y" into one `Tempus::Interval`. The start `X` is resolved as
+/// `Direction::From`, the end `Y` as `Direction::To` and is included in the interval. A bare
+/// weekday, month, holiday or a date without a year as `Y` is the first one on or after `X`:
+/// "Dec 20 - Jan 5" ends in the next year. A range covers whole days: "now" is today, times of
+/// day are rejected.
+fn range(pairs: Pairs<'_, Rule>, cx: &Context) -> Result<Tempus, TimeWarpError> {
+    let mut points = pairs.filter(|pair| pair.as_rule() == Rule::point);
+    let (Some(start), Some(end)) = (points.next(), points.next()) else {
+        return parse_error("No range. Expected start and end");
+    };
+    let (start_span, end_span) = (span_of(&start), span_of(&end));
+    let start = whole_days(start, &cx.with_direction(Direction::From))
+        .map_err(|e| e.at(start_span))?
+        .start();
+    let end_cx = Context {
+        after: Some(start),
+        ..cx.with_direction(Direction::To)
+    };
+    let end = whole_days(end, &end_cx).map_err(|e| e.at(end_span))?.end();
+    if end <= start {
+        return error(
+            ErrorKind::InvalidDate,
+            format!("Range ends before it starts: {start:#} - {end:#}"),
+        );
+    }
+    Ok(Tempus::Interval(start, end))
+}
+
+/// Resolves the start or end of a range into whole days: "now" is just today, a time of day is
+/// rejected.
+fn whole_days(pair: Pair<'_, Rule>, cx: &Context) -> Result<Tempus, TimeWarpError> {
+    let timed = pair
+        .clone()
+        .into_inner()
+        .any(|pair| pair.as_rule() == Rule::time);
+    match point(pair.into_inner(), cx)? {
+        Tempus::Instant(dt) if timed => error(
+            ErrorKind::InvalidDate,
+            format!("Times of day are not supported in ranges: {dt}"),
+        ),
+        Tempus::Instant(dt) => Ok(Tempus::Moment(dt.date)),
+        tempus => Ok(tempus),
+    }
+}
+
+/// Resolves a date, a time or both, in either order: `tomorrow 3 pm` or `3 pm tomorrow`.
+fn point(pairs: Pairs<'_, Rule>, cx: &Context) -> Result<Tempus, TimeWarpError> {
+    let mut tempus = None;
+    let mut time = None;
+    for pair in pairs {
+        let span = span_of(&pair);
+        match pair.as_rule() {
+            Rule::date => {
+                let date = date_of(pair.into_inner(), cx);
+                tempus = Some(date.map_err(|e| e.at(span))?);
+            }
+            Rule::time => time = Some((pair, span)),
+            _ => return parse_error(format!("No point. Found more than expected: {pair:?}")),
+        }
+    }
+    if let Some((pair, span)) = time {
+        let day = tempus.map_or(cx.today, |t| t.start());
+        let dt = time_of(pair.into_inner(), day, cx.direction).map_err(|e| e.at(span))?;
+        tempus = Some(Tempus::Instant(dt));
+    }
+    tempus.map_or_else(|| parse_error("Nothing found"), Ok)
+}
+
+fn date_of(pairs: Pairs<'_, Rule>, cx: &Context) -> Result<Tempus, TimeWarpError> {
+    let (today, direction) = (cx.today, cx.direction);
+    let mut amount = 0i32;
+    let mut forwards = direction == Direction::To;
+    // the end of a range without last, next, ...
+    let mut after = cx.after;
+    for pair in pairs {
+        let span = span_of(&pair);
         match pair.as_rule() {
-            Rule::date_iso | Rule::date_en | Rule::date_de => {
-                return yy_mm_dd(pair.into_inner(), today)
+            Rule::date_de if !cx.accepts(Locale::De) => {
+                return parse_error("No locale accepts dates as d.m.y").map_err(|e| e.at(span))
+            }
+            Rule::date_iso | Rule::date_de => {
+                return yy_mm_dd(pair.into_inner(), cx).map_err(|e| e.at(span))
+            }
+            Rule::date_en => return date_slash(pair.into_inner(), cx).map_err(|e| e.at(span)),
+            Rule::date_long => return date_long(pair.into_inner(), cx).map_err(|e| e.at(span)),
+            Rule::date_kw => return date_week(pair.into_inner(), cx).map_err(|e| e.at(span)),
+            Rule::date_quarter => {
+                return year_part(pair.into_inner(), cx, 3).map_err(|e| e.at(span))
+            }
+            Rule::date_half => return year_part(pair.into_inner(), cx, 6).map_err(|e| e.at(span)),
+            Rule::date_year | Rule::date_decade => {
+                return year_of(pair.into_inner(), cx).map_err(|e| e.at(span))
+            }
+            Rule::date_holiday => return holiday_of(pair.into_inner(), cx).map_err(|e| e.at(span)),
+            Rule::date_month | Rule::date_iso_month => {
+                return year_month(pair.into_inner(), cx).map_err(|e| e.at(span))
+            }
+            Rule::relative => return relative(pair.into_inner(), cx).map_err(|e| e.at(span)),
+            Rule::boundary => return boundary(pair.into_inner(), cx).map_err(|e| e.at(span)),
+            Rule::weekday_of => return weekday_of(pair.into_inner(), cx).map_err(|e| e.at(span)),
+            Rule::calendar_period => {
+                return calendar_period(pair.into_inner(), cx).map_err(|e| e.at(span))
+            }
+            Rule::week_part => return week_part(pair.into_inner(), cx).map_err(|e| e.at(span)),
+            Rule::rolling => return rolling(pair.into_inner(), cx).map_err(|e| e.at(span)),
+            Rule::business_day => {
+                let forwards = pair.into_inner().next().unwrap().as_rule() == Rule::next;
+                let days = if forwards { 1 } else { -1 };
+                return ok_moment(today.add_business_days(days, cx.parser.holidays));
             }
-            Rule::date_long => return date_long(pair.into_inner(), today),
-            Rule::date_kw => return date_week(pair.into_inner(), today),
             Rule::today => return ok_moment(today),
             Rule::yesterday => return ok_moment(today - 1),
             Rule::tomorrow => return ok_moment(today + 1),
-            Rule::last => forwards = false,
-            Rule::next => forwards = true,
-            Rule::amount => amount = i32::from_str(pair.as_str())?,
+            Rule::day_before_yesterday => return ok_moment(today - 2),
+            Rule::day_after_tomorrow => return ok_moment(today + 2),
+            Rule::now => return Ok(now(cx)),
+            Rule::last => (forwards, after) = (false, None),
+            Rule::next => (forwards, after) = (true, None),
+            Rule::amount => {
+                amount =
+                    i32::from_str(pair.as_str()).map_err(|e| TimeWarpError::from(e).at(span))?;
+            }
             Rule::fore_last => {
-                forwards = false;
+                (forwards, after) = (false, None);
                 amount = 1;
             }
             Rule::after_next => {
-                forwards = true;
+                (forwards, after) = (true, None);
                 amount = 1;
             }
             Rule::day_of_week => {
                 let wd_today = today.day_of_week();
                 let target_wd =
                     DayOfWeek::from_day_of_week(pair.into_inner().next().unwrap().as_rule());
-                let date = if forwards {
+                let date = if let Some(after) = after {
+                    after + target_wd.days_before(after.day_of_week()) % 7
+                } else if forwards {
                     today + target_wd.days_before(wd_today) + amount * 7
                 } else {
                     today - wd_today.days_before(target_wd) - amount * 7
@@ -134,15 +419,18 @@ pub fn date_matcher(
             }
             Rule::month => {
                 let month = Month::from_month(pair.into_inner().next().unwrap().as_rule());
-                let date = find_rel_month(today, direction, forwards, month);
-                return ok_moment(date);
+                let date = match after {
+                    Some(after) => find_rel_month(after, true, month),
+                    None => find_rel_month(today, forwards, month),
+                };
+                return Ok(months_of(date.year, month as i32, 1));
             }
             Rule::timeunit => {
                 return ok_moment(find_timeunit(
                     pair.into_inner().next().unwrap().as_rule(),
-                    today,
+                    cx,
                     amount,
-                ))
+                )?)
             }
             _ => return parse_error(format!("date_matcher :: {pair:?}")),
         };
@@ -150,9 +438,488 @@ pub fn date_matcher(
     parse_error("Nothing found")
 }
 
-fn find_rel_month(today: Doy, direction: Direction, future: bool, target_month: Month) -> Doy {
-    // if direction is EndTime add a Month
-    let target_month = target_month + i32::from(direction == Direction::To);
+/// Resolves a time-expression on the given `day`. A part of the day without a clock-time
+/// resolves to its beginning for `Direction::From` and to its end for `Direction::To`.
+fn time_of(
+    pairs: Pairs<'_, Rule>,
+    day: Doy,
+    direction: Direction,
+) -> Result<DateTime, TimeWarpError> {
+    let mut day_part = None;
+    let mut seconds = None;
+    for pair in pairs {
+        match pair.as_rule() {
+            Rule::day_part => {
+                day_part = Some(DayPart::from_day_part(
+                    pair.into_inner().next().unwrap().as_rule(),
+                ));
+            }
+            Rule::clock | Rule::hour_only => seconds = Some(clock(pair.into_inner())?),
+            _ => return parse_error(format!("No time. Found more than expected: {pair:?}")),
+        }
+    }
+    match (day_part, seconds) {
+        (Some(part), Some(seconds)) if (0..24 * 3600).contains(&seconds) => Ok(DateTime::new(
+            day,
+            part.adjust(TimeOfDay::from_seconds(seconds)),
+        )),
+        (Some(part), None) if direction == Direction::To => Ok(part.end(day)),
+        (Some(part), None) => Ok(part.start(day)),
+        // "halb null" is 23:30 of the previous day
+        (_, Some(seconds)) => Ok(DateTime::start_of_day(day).add_seconds(seconds)),
+        (None, None) => parse_error("No time found"),
+    }
+}
+
+/// The seconds since midnight of the clock in `pairs`, negative for "halb null".
+fn clock(pairs: Pairs<'_, Rule>) -> Result<i32, TimeWarpError> {
+    let mut hour = 0;
+    let mut minute = 0;
+    let mut second = 0;
+    let mut offset = 0;
+    for pair in pairs {
+        match pair.as_rule() {
+            Rule::hh => hour = i32::from_str(pair.as_str())?,
+            Rule::number_word => hour = number_of(&pair, 0..=23)?,
+            Rule::mi => minute = i32::from_str(pair.as_str())?,
+            Rule::ss => second = i32::from_str(pair.as_str())?,
+            Rule::half => offset = -30,
+            Rule::half_past => offset = 30,
+            Rule::quarter_past => offset = 15,
+            Rule::quarter_to => offset = -15,
+            Rule::am | Rule::pm => {
+                if !(1..=12).contains(&hour) {
+                    return parse_error(format!("No 12h-clock: '{hour}'"));
+                }
+                hour = hour % 12 + if pair.as_rule() == Rule::pm { 12 } else { 0 };
+            }
+            _ => return parse_error(format!("No clock. Found more than expected: {pair:?}")),
+        }
+    }
+    Ok((hour * 60 + minute + offset) * 60 + second)
+}
+
+/// The value of the number word in `pair`, which has to be within `range`.
+fn number_of(pair: &Pair<'_, Rule>, range: RangeInclusive<i32>) -> Result<i32, TimeWarpError> {
+    match number_words::number(pair.as_str()) {
+        Some(n) if range.contains(&n) => Ok(n),
+        _ => error(
+            ErrorKind::InvalidDate,
+            format!("Number out of range {range:?}: '{}'", pair.as_str()),
+        ),
+    }
+}
+
+/// The value of "3.", "2nd", "third" or "dritter" in `pair`, which has to be within `range`.
+fn ordinal_of(pair: &Pair<'_, Rule>, range: RangeInclusive<i32>) -> Result<i32, TimeWarpError> {
+    let word = pair.as_str();
+    let digits: String = word.chars().take_while(char::is_ascii_digit).collect();
+    let n = if digits.is_empty() {
+        number_words::ordinal(word)
+    } else {
+        i32::from_str(&digits).ok()
+    };
+    match n {
+        Some(n) if range.contains(&n) => Ok(n),
+        _ => error(
+            ErrorKind::InvalidDate,
+            format!("Ordinal out of range {range:?}: '{word}'"),
+        ),
+    }
+}
+
+/// Resolves "now" or "jetzt" into the current instant. A parser with a fixed reference date
+/// knows no time of day, so there it is just that day.
+fn now(cx: &Context) -> Tempus {
+    match cx.parser.today {
+        Some(today) => Tempus::Moment(today),
+        None => Tempus::Instant(DateTime::now()),
+    }
+}
+
+/// Resolves "in 3 days", "2 weeks ago", "vor 2 Monaten" or "heute in einer Woche".
+fn relative(pairs: Pairs<'_, Rule>, cx: &Context) -> Result<Tempus, TimeWarpError> {
+    let mut today = cx.today;
+    let mut sign = 1;
+    let mut amount = 1;
+    let mut unit = None;
+    for pair in pairs {
+        match pair.as_rule() {
+            Rule::day_before_yesterday => today = today - 2,
+            Rule::yesterday => today = today - 1,
+            Rule::tomorrow => today = today + 1,
+            Rule::day_after_tomorrow => today = today + 2,
+            Rule::today | Rule::later => {}
+            Rule::before | Rule::ago => sign = -1,
+            Rule::count => amount = count_of(&pair)?,
+            Rule::timeunit => unit = Some(pair.into_inner().next().unwrap().as_rule()),
+            _ => {
+                return parse_error(format!(
+                    "No relative date. Found more than expected: {pair:?}"
+                ))
+            }
+        }
+    }
+    let Some(unit) = unit else {
+        return parse_error("No relative date. Expected a unit of time");
+    };
+    ok_moment(find_timeunit(
+        unit,
+        &Context { today, ..*cx },
+        sign * amount,
+    )?)
+}
+
+/// Resolves "last 7 days", "letzte 2 Wochen" or "next 10 days" into a rolling window, which
+/// always includes today: the last 7 days end with today, the next 7 days start with today.
+fn rolling(pairs: Pairs<'_, Rule>, cx: &Context) -> Result<Tempus, TimeWarpError> {
+    let mut forwards = true;
+    let mut amount = 1;
+    let mut unit = None;
+    for pair in pairs {
+        match pair.as_rule() {
+            Rule::last | Rule::recent => forwards = false,
+            Rule::next => forwards = true,
+            Rule::count => amount = count_of(&pair)?,
+            Rule::timeunit => unit = Some(pair.into_inner().next().unwrap().as_rule()),
+            _ => {
+                return parse_error(format!(
+                    "No rolling window. Found more than expected: {pair:?}"
+                ))
+            }
+        }
+    }
+    let Some(unit) = unit else {
+        return parse_error("No rolling window. Expected a unit of time");
+    };
+    if amount == 0 {
+        return error(ErrorKind::InvalidDate, "Rolling window is empty");
+    }
+    // business days are counted from the day before (or after) the base, so the window starts
+    // (or ends) with one and holds all of them, also if the base is on a weekend
+    let business_days = unit == Rule::business_days;
+    if forwards {
+        let end = if business_days {
+            find_timeunit(
+                unit,
+                &Context {
+                    today: cx.today - 1,
+                    ..*cx
+                },
+                amount,
+            )? + 1
+        } else {
+            find_timeunit(unit, cx, amount)?
+        };
+        Ok(Tempus::Interval(cx.today, end))
+    } else {
+        let start = if business_days {
+            find_timeunit(
+                unit,
+                &Context {
+                    today: cx.today + 1,
+                    ..*cx
+                },
+                -amount,
+            )?
+        } else {
+            find_timeunit(unit, cx, -amount)? + 1
+        };
+        Ok(Tempus::Interval(start, cx.today + 1))
+    }
+}
+
+/// The amount of "3", "drei", "a" or "einer".
+fn count_of(pair: &Pair<'_, Rule>) -> Result<i32, TimeWarpError> {
+    match pair.clone().into_inner().next() {
+        Some(number) => number_of(&number, 0..=99),
+        None if pair.as_str().starts_with(|c: char| c.is_ascii_digit()) => {
+            Ok(i32::from_str(pair.as_str())?)
+        }
+        None => Ok(1),
+    }
+}
+
+/// Resolves "this week", "next month", "letztes Jahr", ... into the whole week, month, quarter,
+/// half-year, year or decade.
+fn calendar_period(pairs: Pairs<'_, Rule>, cx: &Context) -> Result<Tempus, TimeWarpError> {
+    let mut amount = 0;
+    for pair in pairs {
+        match pair.as_rule() {
+            Rule::this => amount = 0,
+            Rule::last => amount = -1,
+            Rule::fore_last => amount = -2,
+            Rule::next => amount = 1,
+            Rule::after_next => amount = 2,
+            unit => return period_of(unit, amount, cx),
+        }
+    }
+    parse_error("No period. Expected week, month, quarter, half-year, year or decade")
+}
+
+/// The whole week, month, ... `amount` periods of the `unit` away from today.
+fn period_of(unit: Rule, amount: i32, cx: &Context) -> Result<Tempus, TimeWarpError> {
+    let today = cx.today;
+    let months = match unit {
+        Rule::weeks => {
+            let start = today.start_of_week(cx.parser.week_start) + amount * 7;
+            return Ok(Tempus::Interval(start, start + 7));
+        }
+        Rule::decades => {
+            let year = today.year - today.year.rem_euclid(10) + amount * 10;
+            return Ok(months_of(year, 1, 120));
+        }
+        Rule::months => 1,
+        Rule::quarters => 3,
+        Rule::halves => 6,
+        Rule::years => 12,
+        _ => return parse_error(format!("No period. Found more than expected: {unit:?}")),
+    };
+    let first = (today.month() as i32 - 1) / months * months + 1;
+    Ok(months_of(today.year, first + amount * months, months))
+}
+
+/// Resolves "end of next week", "Anfang März", "Mitte des Monats", "Jahresende" or "ultimo" into
+/// the first, the middle or the last day of that period.
+fn boundary(mut pairs: Pairs<'_, Rule>, cx: &Context) -> Result<Tempus, TimeWarpError> {
+    let Some(pair) = pairs.next() else {
+        return parse_error("No boundary. Expected beginning, middle or end");
+    };
+    let (position, unit, day) = match pair.as_rule() {
+        Rule::boundary_word => {
+            let word = pair.as_str().to_lowercase();
+            let unit = match &word {
+                w if w.starts_with("wochen") => Rule::weeks,
+                w if w.starts_with("monats") => Rule::months,
+                w if w.starts_with("quartals") => Rule::quarters,
+                _ => Rule::years,
+            };
+            let position = match &word {
+                w if w.ends_with("ende") => Rule::period_end,
+                w if w.ends_with("mitte") => Rule::period_middle,
+                _ => Rule::period_start,
+            };
+            (position, unit, cx.today)
+        }
+        Rule::ultimo => (Rule::period_end, Rule::months, cx.today),
+        Rule::medio => (Rule::period_middle, Rule::months, cx.today),
+        position => match pairs.peek().map(|pair| (pair.as_rule(), pair)) {
+            Some((
+                unit @ (Rule::weeks | Rule::months | Rule::quarters | Rule::halves | Rule::years),
+                _,
+            )) => (position, unit, cx.today),
+            Some((rule, period)) => {
+                let unit = match rule {
+                    Rule::calendar_period => period.into_inner().last().unwrap().as_rule(),
+                    Rule::date_quarter => Rule::quarters,
+                    Rule::date_half => Rule::halves,
+                    Rule::date_year => Rule::years,
+                    _ => Rule::months,
+                };
+                (position, unit, date_of(pairs, cx)?.start())
+            }
+            None => return parse_error("No boundary. Expected a period"),
+        },
+    };
+    ok_moment(boundary_of(position, unit, day, cx))
+}
+
+/// The first, middle or last day of the week, month, quarter, half-year, year or decade of `day`.
+/// The middle of a half-year or a decade is the first day of its second half.
+fn boundary_of(position: Rule, unit: Rule, day: Doy, cx: &Context) -> Doy {
+    let week_start = cx.parser.week_start;
+    let (start, half, end) = match unit {
+        Rule::weeks => (
+            day.start_of_week(week_start),
+            day.middle_of_week(week_start),
+            day.end_of_week(week_start),
+        ),
+        Rule::quarters => (
+            day.start_of_quarter(),
+            day.middle_of_quarter(),
+            day.end_of_quarter(),
+        ),
+        Rule::halves => {
+            let start = Doy::from_ymd(day.year, if day.month() as i32 <= 6 { 1 } else { 7 }, 1);
+            (start, start.add_months(3), start.add_months(6) - 1)
+        }
+        Rule::years => (day.start_of_year(), day.middle_of_year(), day.end_of_year()),
+        Rule::decades => {
+            let start = Doy::from_ymd(day.year - day.year.rem_euclid(10), 1, 1);
+            (start, start.add_years(5), start.add_years(10) - 1)
+        }
+        _ => (
+            day.start_of_month(),
+            day.middle_of_month(),
+            day.end_of_month(),
+        ),
+    };
+    match position {
+        Rule::period_start => start,
+        Rule::period_end => end,
+        _ => half,
+    }
+}
+
+/// Resolves "first Monday in May", "2nd Tuesday of next month" or "letzter Freitag im Monat"
+/// into the n-th (or the final) occurrence of the weekday in that month.
+fn weekday_of(mut pairs: Pairs<'_, Rule>, cx: &Context) -> Result<Tempus, TimeWarpError> {
+    let (Some(nth), Some(weekday)) = (pairs.next(), pairs.next()) else {
+        return parse_error("No weekday of month. Expected ordinal and weekday");
+    };
+    let n = match nth.as_rule() {
+        Rule::last => -1,
+        _ => ordinal_of(&nth, 1..=5)?,
+    };
+    let weekday = DayOfWeek::from_day_of_week(weekday.into_inner().next().unwrap().as_rule());
+    let month = match pairs.peek().map(|pair| pair.as_rule()) {
+        Some(Rule::months) => period_of(Rule::months, 0, cx)?,
+        _ => date_of(pairs, cx)?,
+    };
+    let (start, end) = (month.start(), month.end());
+    let date = if n < 0 {
+        let last_day: Doy = end - 1;
+        last_day - last_day.day_of_week().days_before(weekday) % 7
+    } else {
+        start + weekday.days_before(start.day_of_week()) % 7 + (n - 1) * 7
+    };
+    if date >= end {
+        return error(
+            ErrorKind::InvalidDate,
+            format!("No {n}. {weekday:?} from {start:#} to {:#}", end - 1),
+        );
+    }
+    ok_moment(date)
+}
+
+/// The `count` months starting with the `month` of the `year`, months out of range roll over
+/// into the neighbouring years.
+fn months_of(year: i32, month: i32, count: i32) -> Tempus {
+    Tempus::Interval(
+        Doy::from_ymd(year, month, 1),
+        Doy::from_ymd(year, month + count, 1),
+    )
+}
+
+/// Resolves "2024", "im Jahr 2024" or "'23" into the whole year, "1990s" or "90er" into the
+/// whole decade. A two-digit decade is the most recent one that has started: "the 70s" are
+/// 1970 to 1979.
+fn year_of(mut pairs: Pairs<'_, Rule>, cx: &Context) -> Result<Tempus, TimeWarpError> {
+    let Some(pair) = pairs.next() else {
+        return parse_error("No year. Expected a year or decade");
+    };
+    let yy = i32::from_str(pair.as_str())?;
+    match pair.as_rule() {
+        Rule::yyyy => Ok(months_of(cx.full_year(yy), 1, 12)),
+        Rule::decade_no if yy < 100 => {
+            let today = cx.today.year;
+            let year = today - today.rem_euclid(100) + yy;
+            let started = if year > today { year - 100 } else { year };
+            Ok(months_of(started, 1, 120))
+        }
+        Rule::decade_no => Ok(months_of(yy, 1, 120)),
+        _ => parse_error(format!("No year. Found more than expected: {pair:?}")),
+    }
+}
+
+/// Resolves "Q3 2023", "2. Quartal", "H1/24" or "2023-H2" into the whole quarter or half-year.
+fn year_part(pairs: Pairs<'_, Rule>, cx: &Context, months: i32) -> Result<Tempus, TimeWarpError> {
+    let mut yy = cx.today.year;
+    let mut no = 1;
+    for pair in pairs {
+        match pair.as_rule() {
+            Rule::yyyy => yy = cx.full_year(i32::from_str(pair.as_str())?),
+            Rule::quarter_no | Rule::half_no => no = i32::from_str(pair.as_str())?,
+            Rule::ordinal_word => no = ordinal_of(&pair, 1..=12 / months)?,
+            _ => return parse_error(format!("No quarter. Found more than expected: {pair:?}")),
+        }
+    }
+    Ok(months_of(yy, (no - 1) * months + 1, months))
+}
+
+/// Resolves "Wochenende", "next weekend" or "this workweek" into Saturday to Sunday or Monday
+/// to Friday of the (Monday-based) week of `today`, or of the weeks before or after.
+fn week_part(pairs: Pairs<'_, Rule>, cx: &Context) -> Result<Tempus, TimeWarpError> {
+    let mut weeks = 0;
+    let mut weekend = false;
+    for pair in pairs {
+        match pair.as_rule() {
+            Rule::this => weeks = 0,
+            Rule::last => weeks = -1,
+            Rule::next => weeks = 1,
+            Rule::fore_last => weeks = -2,
+            Rule::after_next => weeks = 2,
+            Rule::weekend => weekend = true,
+            Rule::workweek => weekend = false,
+            _ => return parse_error(format!("No weekend. Found more than expected: {pair:?}")),
+        }
+    }
+    let monday = cx.today.start_of_week(DayOfWeek::Mon).add_weeks(weeks);
+    if weekend {
+        Ok(Tempus::Interval(monday + 5, monday + 7))
+    } else {
+        Ok(Tempus::Interval(monday, monday + 5))
+    }
+}
+
+/// Resolves "Ostern 2024", "next Christmas" or "Heiligabend". Without a year it is the last (or
+/// next) occurrence like a month, today included. "bis Weihnachten" is always the next one,
+/// "after Easter" and "vor Weihnachten" are the day after or before the holiday.
+fn holiday_of(pairs: Pairs<'_, Rule>, cx: &Context) -> Result<Tempus, TimeWarpError> {
+    let mut forwards = cx.direction == Direction::To;
+    let mut skip = 0;
+    let mut this = false;
+    let mut year = None;
+    let mut holiday = None;
+    // the day after or before the holiday
+    let mut shift = 0;
+    // the end of a range without last, next, ...
+    let mut after = cx.after;
+    for pair in pairs {
+        match pair.as_rule() {
+            Rule::till => forwards = true,
+            Rule::after => shift = 1,
+            Rule::prior => shift = -1,
+            Rule::this => this = true,
+            Rule::last => (forwards, after) = (false, None),
+            Rule::next => (forwards, after) = (true, None),
+            Rule::fore_last => (forwards, skip, after) = (false, 1, None),
+            Rule::after_next => (forwards, skip, after) = (true, 1, None),
+            Rule::holiday => {
+                holiday = Holiday::from_holiday(pair.into_inner().next().unwrap().as_rule());
+            }
+            Rule::yyyy => year = Some(cx.full_year(i32::from_str(pair.as_str())?)),
+            _ => return parse_error(format!("No holiday. Found more than expected: {pair:?}")),
+        }
+    }
+    let Some(holiday) = holiday else {
+        return parse_error("No holiday found");
+    };
+    let date = match (year, after) {
+        (Some(year), _) => holiday.date(year),
+        (None, _) if this => holiday.date(cx.today.year),
+        (None, Some(after)) => find_rel_holiday(after, true, holiday),
+        (None, _) => {
+            let date = find_rel_holiday(cx.today, forwards, holiday);
+            let year = date.year + if forwards { skip } else { -skip };
+            holiday.date(year)
+        }
+    };
+    ok_moment(date + shift)
+}
+
+/// The last (or next) occurrence of `holiday`, today included.
+fn find_rel_holiday(today: Doy, future: bool, holiday: Holiday) -> Doy {
+    let date = holiday.date(today.year);
+    match (future, date.cmp(&today)) {
+        (true, Ordering::Less) => holiday.date(today.year + 1),
+        (false, Ordering::Greater) => holiday.date(today.year - 1),
+        _ => date,
+    }
+}
+
+/// The first day of the last (or next) `target_month`, the current month included.
+fn find_rel_month(today: Doy, future: bool, target_month: Month) -> Doy {
     let today_m = today.month();
     let add = if target_month > today_m && !future {
         -1
@@ -162,34 +929,85 @@ fn find_rel_month(today: Doy, direction: Direction, future: bool, target_month:
     Doy::from_ymd(today.year + add, target_month as i32, 1)
 }
 
-fn find_timeunit(rule: Rule, today: Doy, amount: i32) -> Doy {
+/// The most business days added at once, about 4000 years.
+const MAX_BUSINESS_DAYS: u32 = 1_000_000;
+
+fn find_timeunit(rule: Rule, cx: &Context, amount: i32) -> Result<Doy, TimeWarpError> {
+    let (today, end_of_month) = (cx.today, cx.parser.end_of_month);
+    let out_of_range = || error(ErrorKind::Overflow, format!("Out of range: {amount}"));
+    // the resulting year has to fit into an i32
+    let years = match rule {
+        Rule::days => amount / 365,
+        Rule::business_days => amount / 250,
+        Rule::weeks | Rule::fortnights => amount / 52,
+        Rule::months => amount / 12,
+        Rule::quarters => amount / 4,
+        Rule::halves => amount / 2,
+        Rule::decades => amount.saturating_mul(10),
+        _ => amount,
+    };
+    if today.year.checked_add(years).is_none()
+        || rule == Rule::business_days && amount.unsigned_abs() > MAX_BUSINESS_DAYS
+    {
+        return out_of_range();
+    }
     match rule {
-        Rule::days => today + amount,
-        Rule::months => {
-            let mut m = today.month() as i32 + amount;
-            let mut y = today.year;
-            while m > 12 {
-                m -= 12;
-                y += 1;
-            }
-            while m < 1 {
-                m += 12;
-                y -= 1;
-            }
-            Doy::from_ymd(y, m, today.day_of_month())
-        }
-        Rule::years => Doy::new(today.doy, today.year + amount),
-        _ => today,
+        Rule::days => Ok(today.add_days(amount)),
+        Rule::business_days => Ok(today.add_business_days(amount, cx.parser.holidays)),
+        Rule::weeks => Ok(today.add_weeks(amount)),
+        Rule::fortnights => match amount.checked_mul(2) {
+            Some(weeks) => Ok(today.add_weeks(weeks)),
+            None => out_of_range(),
+        },
+        Rule::months => Ok(today.add_months_with(amount, end_of_month)),
+        Rule::quarters => match amount.checked_mul(3) {
+            Some(months) => Ok(today.add_months_with(months, end_of_month)),
+            None => out_of_range(),
+        },
+        Rule::halves => match amount.checked_mul(6) {
+            Some(months) => Ok(today.add_months_with(months, end_of_month)),
+            None => out_of_range(),
+        },
+        Rule::years => Ok(today.add_years_with(amount, end_of_month)),
+        Rule::decades => match amount.checked_mul(10) {
+            Some(years) => Ok(today.add_years_with(years, end_of_month)),
+            None => out_of_range(),
+        },
+        _ => parse_error(format!("Unknown unit of time: {rule:?}")),
     }
 }
 
 #[cfg(test)]
 mod should {
     use crate::date_matcher;
+    use crate::date_matcher::date_matcher_strict;
     use crate::date_matcher::{correct_yyyy, find_rel_month};
     use crate::Direction::{From, To};
-    use crate::Month::{Aug, Jan};
-    use crate::{Doy, Tempus};
+    use crate::Month::{Aug, Jan, Mar};
+    use crate::{DateTime, Doy, ErrorKind, Tempus, TimeOfDay};
+
+    fn moment(y: i32, m: i32, d: i32) -> Tempus {
+        Tempus::Moment(Doy::from_ymd(y, m, d))
+    }
+
+    fn interval(start: (i32, i32, i32), end: (i32, i32, i32)) -> Tempus {
+        Tempus::Interval(
+            Doy::from_ymd(start.0, start.1, start.2),
+            Doy::from_ymd(end.0, end.1, end.2),
+        )
+    }
+
+    fn months(y: i32, m: i32, count: i32) -> Tempus {
+        Tempus::Interval(Doy::from_ymd(y, m, 1), Doy::from_ymd(y, m + count, 1))
+    }
+
+    fn years(y: i32, count: i32) -> Tempus {
+        Tempus::Interval(Doy::from_ymd(y, 1, 1), Doy::from_ymd(y + count, 1, 1))
+    }
+
+    fn at(date: Doy, h: i32, m: i32) -> Tempus {
+        Tempus::Instant(DateTime::new(date, TimeOfDay::new(h, m)))
+    }
 
     #[test]
     fn adjust_yyyy() {
@@ -204,114 +1022,965 @@ mod should {
     fn find_relative_months() {
         // Fri 2023-03-17
         let today = Doy::from_ymd(2023, 3, 17);
-        assert_eq!(Doy::new(1, 2023), find_rel_month(today, From, false, Jan));
+        assert_eq!(Doy::new(1, 2023), find_rel_month(today, false, Jan));
 
         assert_eq!(
-            Tempus::Moment(Doy::new(1, 2023)),
-            date_matcher(today, From, "last january").unwrap(),
+            months(2023, 1, 1),
+            date_matcher(today, From, "last january").unwrap()
+        );
+        assert_eq!(
+            months(2024, 1, 1),
+            date_matcher(today, From, "next january").unwrap()
         );
+        assert_eq!(Doy::from_ymd(2023, 8, 1), find_rel_month(today, true, Aug));
+        assert_eq!(Doy::from_ymd(2022, 8, 1), find_rel_month(today, false, Aug));
+        assert_eq!(Doy::from_ymd(2023, 3, 1), find_rel_month(today, false, Mar));
+        assert_eq!(Doy::from_ymd(2023, 3, 1), find_rel_month(today, true, Mar));
         assert_eq!(
-            Tempus::Moment(Doy::new(1, 2024)),
-            date_matcher(today, From, "next january").unwrap(),
+            months(2024, 1, 1),
+            date_matcher(today, To, "next january").unwrap()
         );
         assert_eq!(
-            Doy::from_ymd(2023, 9, 1),
-            find_rel_month(today, To, true, Aug)
+            months(2023, 8, 1),
+            date_matcher(today, To, "August").unwrap()
         );
         assert_eq!(
-            Doy::from_ymd(2022, 8, 1),
-            find_rel_month(today, From, false, Aug)
+            months(2022, 8, 1),
+            date_matcher(today, From, "August").unwrap()
         );
         assert_eq!(
-            Doy::from_ymd(2022, 9, 1),
-            find_rel_month(today, To, false, Aug)
+            months(2023, 3, 1),
+            date_matcher(today, From, "in March").unwrap()
         );
         assert_eq!(
-            Tempus::Moment(Doy::from_ymd(2024, 2, 1)),
-            date_matcher(today, To, "next january").unwrap(),
+            months(2022, 5, 1),
+            date_matcher(today, From, "im Mai").unwrap()
+        );
+        assert_eq!(
+            interval((2022, 12, 1), (2023, 3, 1)),
+            date_matcher(today, From, "from last December to last February").unwrap()
         );
     }
 
     #[test]
-    fn find_relative_dates() {
+    fn parse_year_months() {
         // Fri 2023-03-17
         let today = Doy::from_ymd(2023, 3, 17);
         assert_eq!(
-            Tempus::Moment(Doy::from_ymd(2023, 3, 13)),
-            date_matcher(today, To, "last monday").unwrap(),
-        );
-        assert_eq!(
-            Tempus::Moment(Doy::from_ymd(2023, 3, 14)),
-            date_matcher(today, From, "tuesday").unwrap(),
-        );
-        assert_eq!(
-            Tempus::Moment(Doy::from_ymd(2023, 3, 21)),
-            date_matcher(today, To, "tuesday").unwrap(),
+            months(2023, 3, 1),
+            date_matcher(today, From, "März 2023").unwrap()
         );
         assert_eq!(
-            Tempus::Moment(Doy::from_ymd(2023, 3, 16)),
-            date_matcher(today, From, "letzten donnerstag").unwrap(),
+            months(2023, 3, 1),
+            date_matcher(today, From, "March 2023").unwrap()
         );
         assert_eq!(
-            Tempus::Moment(Doy::from_ymd(2023, 3, 10)),
-            date_matcher(today, To, "last friday").unwrap(),
+            months(2023, 3, 1),
+            date_matcher(today, From, "Mar '23").unwrap()
         );
         assert_eq!(
-            Tempus::Moment(Doy::from_ymd(2023, 3, 24)),
-            date_matcher(today, To, "nächsten Fr").unwrap(),
+            months(2021, 12, 1),
+            date_matcher(today, From, "im Dezember 2021").unwrap()
         );
         assert_eq!(
-            Tempus::Moment(Doy::from_ymd(2023, 3, 23)),
-            date_matcher(today, To, "coming Thu").unwrap(),
+            months(2023, 3, 1),
+            date_matcher(today, From, "2023-03").unwrap()
         );
         assert_eq!(
-            Tempus::Moment(Doy::from_ymd(2023, 3, 30)),
-            date_matcher(today, To, "übernächsten Donnerstag").unwrap(),
+            months(2024, 12, 1),
+            date_matcher(today, From, "2024-12").unwrap()
         );
         assert_eq!(
-            Tempus::Moment(Doy::from_ymd(2023, 3, 20)),
-            date_matcher(today, To, "nächster Mo").unwrap(),
+            moment(2023, 3, 20),
+            date_matcher(today, From, "March 20 2023").unwrap()
         );
         assert_eq!(
-            Tempus::Moment(Doy::from_ymd(2023, 3, 6)),
-            date_matcher(today, To, "vorletzter mo").unwrap(),
+            interval((2023, 1, 1), (2023, 4, 1)),
+            date_matcher(today, From, "2023-01 - 2023-03").unwrap()
         );
+        assert!(date_matcher(today, From, "2023-13").is_err());
     }
 
     #[test]
-    fn find_yesterday() {
-        let first_of_march = Doy::from_ymd(2023, 3, 1);
-        assert_eq!(
-            Tempus::Moment(Doy::from_ymd(2023, 3, 1)),
+    fn find_relative_dates() {
+        // Fri 2023-03-17
+        let today = Doy::from_ymd(2023, 3, 17);
+        assert_eq!(
+            moment(2023, 3, 13),
+            date_matcher(today, To, "last monday").unwrap(),
+        );
+        assert_eq!(
+            moment(2023, 3, 14),
+            date_matcher(today, From, "tuesday").unwrap(),
+        );
+        assert_eq!(
+            moment(2023, 3, 21),
+            date_matcher(today, To, "tuesday").unwrap(),
+        );
+        assert_eq!(
+            moment(2023, 3, 16),
+            date_matcher(today, From, "letzten donnerstag").unwrap(),
+        );
+        assert_eq!(
+            moment(2023, 3, 10),
+            date_matcher(today, To, "last friday").unwrap(),
+        );
+        assert_eq!(
+            moment(2023, 3, 24),
+            date_matcher(today, To, "nächsten Fr").unwrap(),
+        );
+        assert_eq!(
+            moment(2023, 3, 23),
+            date_matcher(today, To, "coming Thu").unwrap(),
+        );
+        assert_eq!(
+            moment(2023, 3, 30),
+            date_matcher(today, To, "übernächsten Donnerstag").unwrap(),
+        );
+        assert_eq!(
+            moment(2023, 3, 20),
+            date_matcher(today, To, "nächster Mo").unwrap(),
+        );
+        assert_eq!(
+            moment(2023, 3, 6),
+            date_matcher(today, To, "vorletzter mo").unwrap(),
+        );
+    }
+
+    #[test]
+    fn find_calendar_periods() {
+        // Fri 2023-03-17
+        let today = Doy::from_ymd(2023, 3, 17);
+        assert_eq!(
+            interval((2023, 3, 13), (2023, 3, 20)),
+            date_matcher(today, From, "this week").unwrap(),
+        );
+        assert_eq!(
+            interval((2023, 3, 20), (2023, 3, 27)),
+            date_matcher(today, To, "kommende Woche").unwrap(),
+        );
+        assert_eq!(
+            interval((2023, 3, 27), (2023, 4, 3)),
+            date_matcher(today, From, "übernächste Woche").unwrap(),
+        );
+        assert_eq!(
+            interval((2023, 2, 1), (2023, 3, 1)),
+            date_matcher(today, From, "last month").unwrap(),
+        );
+        assert_eq!(
+            interval((2023, 3, 1), (2023, 4, 1)),
+            date_matcher(today, From, "diesen Monat").unwrap(),
+        );
+        assert_eq!(
+            interval((2023, 4, 1), (2023, 5, 1)),
+            date_matcher(today, From, "nächsten Monat").unwrap(),
+        );
+        assert_eq!(
+            interval((2022, 1, 1), (2023, 1, 1)),
+            date_matcher(today, From, "letztes Jahr").unwrap(),
+        );
+        assert_eq!(
+            interval((2021, 1, 1), (2022, 1, 1)),
+            date_matcher(today, From, "vorletztes Jahr").unwrap(),
+        );
+        assert_eq!(
+            interval((2023, 1, 1), (2024, 1, 1)),
+            date_matcher(today, To, "dieses Jahr").unwrap(),
+        );
+        let new_year = Doy::from_ymd(2023, 1, 1);
+        assert_eq!(
+            interval((2022, 12, 26), (2023, 1, 2)),
+            date_matcher(new_year, From, "current week").unwrap(),
+        );
+        assert_eq!(
+            interval((2022, 12, 1), (2023, 1, 1)),
+            date_matcher(new_year, From, "previous month").unwrap(),
+        );
+        assert_eq!(
+            interval((2023, 3, 6), (2023, 3, 20)),
+            date_matcher(today, From, "from last week to this week").unwrap(),
+        );
+    }
+
+    #[test]
+    fn parse_quarters() {
+        // Fri 2023-03-17
+        let today = Doy::from_ymd(2023, 3, 17);
+        assert_eq!(
+            months(2023, 7, 3),
+            date_matcher(today, From, "Q3 2023").unwrap()
+        );
+        assert_eq!(
+            months(2022, 10, 3),
+            date_matcher(today, From, "q4/22").unwrap()
+        );
+        assert_eq!(
+            months(2024, 1, 3),
+            date_matcher(today, From, "2024-Q1").unwrap()
+        );
+        assert_eq!(months(2023, 4, 3), date_matcher(today, From, "Q2").unwrap());
+        assert_eq!(
+            months(2023, 4, 3),
+            date_matcher(today, From, "2. Quartal").unwrap()
+        );
+        assert_eq!(
+            months(2021, 7, 3),
+            date_matcher(today, From, "3rd quarter 2021").unwrap()
+        );
+        assert_eq!(
+            months(2024, 1, 6),
+            date_matcher(today, From, "H1/24").unwrap()
+        );
+        assert_eq!(
+            months(2023, 7, 6),
+            date_matcher(today, From, "2023-H2").unwrap()
+        );
+        assert_eq!(
+            months(2023, 7, 6),
+            date_matcher(today, From, "2. Halbjahr").unwrap()
+        );
+        assert_eq!(
+            months(2022, 1, 6),
+            date_matcher(today, From, "1st half 2022").unwrap()
+        );
+        assert_eq!(
+            months(2023, 1, 3),
+            date_matcher(today, From, "this quarter").unwrap()
+        );
+        assert_eq!(
+            months(2022, 10, 3),
+            date_matcher(today, From, "last quarter").unwrap()
+        );
+        assert_eq!(
+            months(2023, 4, 3),
+            date_matcher(today, From, "nächstes Quartal").unwrap()
+        );
+        assert_eq!(
+            months(2023, 7, 6),
+            date_matcher(today, From, "nächstes Halbjahr").unwrap()
+        );
+        assert_eq!(
+            months(2022, 7, 6),
+            date_matcher(today, From, "last half-year").unwrap()
+        );
+        assert_eq!(
+            months(2023, 1, 6),
+            date_matcher(today, To, "Q1 - Q2").unwrap()
+        );
+        assert!(date_matcher(today, From, "Q5 2023").is_err());
+        assert!(date_matcher(today, From, "H3").is_err());
+    }
+
+    #[test]
+    fn parse_years_and_decades() {
+        // Fri 2023-03-17
+        let today = Doy::from_ymd(2023, 3, 17);
+        assert_eq!(years(2024, 1), date_matcher(today, From, "2024").unwrap());
+        assert_eq!(
+            years(2024, 1),
+            date_matcher(today, From, "im Jahr 2024").unwrap()
+        );
+        assert_eq!(
+            years(1989, 1),
+            date_matcher(today, From, "in the year 1989").unwrap()
+        );
+        assert_eq!(
+            years(2021, 1),
+            date_matcher(today, From, "in 2021").unwrap()
+        );
+        assert_eq!(years(2023, 1), date_matcher(today, From, "'23").unwrap());
+        assert_eq!(years(1999, 1), date_matcher(today, From, "'99").unwrap());
+        assert_eq!(
+            years(2020, 5),
+            date_matcher(today, From, "2020 - 2024").unwrap()
+        );
+        assert_eq!(years(1990, 10), date_matcher(today, From, "1990s").unwrap());
+        assert_eq!(
+            years(1990, 10),
+            date_matcher(today, From, "the 90s").unwrap()
+        );
+        assert_eq!(
+            years(1980, 10),
+            date_matcher(today, From, "the '80s").unwrap()
+        );
+        assert_eq!(years(1990, 10), date_matcher(today, From, "90er").unwrap());
+        assert_eq!(
+            years(1970, 10),
+            date_matcher(today, From, "the 70s").unwrap()
+        );
+        assert_eq!(years(1960, 10), date_matcher(today, From, "60er").unwrap());
+        assert_eq!(
+            years(2020, 10),
+            date_matcher(today, From, "in den 20ern").unwrap()
+        );
+        assert_eq!(
+            years(2010, 10),
+            date_matcher(today, From, "letztes Jahrzehnt").unwrap()
+        );
+        assert_eq!(
+            years(2020, 10),
+            date_matcher(today, From, "this decade").unwrap()
+        );
+        assert_eq!(
+            years(2030, 10),
+            date_matcher(today, From, "next decade").unwrap()
+        );
+        assert_eq!(
+            moment(2043, 3, 17),
+            date_matcher(today, From, "+2 Jahrzehnte").unwrap(),
+        );
+        assert!(date_matcher(today, From, "20245").is_err());
+        assert!(date_matcher(today, From, "1995s").is_err());
+    }
+
+    #[test]
+    fn find_weekday_of_month() {
+        // Fri 2023-03-17
+        let today = Doy::from_ymd(2023, 3, 17);
+        assert_eq!(
+            moment(2023, 5, 1),
+            date_matcher(today, To, "first Monday in May").unwrap()
+        );
+        // a bare month is the last one as From
+        assert_eq!(
+            moment(2022, 5, 2),
+            date_matcher(today, From, "first Monday in May").unwrap()
+        );
+        assert_eq!(
+            moment(2023, 3, 6),
+            date_matcher(today, From, "the first Monday of this month").unwrap()
+        );
+        assert_eq!(
+            moment(2023, 5, 1),
+            date_matcher(today, To, "erster Montag im Mai").unwrap()
+        );
+        assert_eq!(
+            moment(2023, 4, 11),
+            date_matcher(today, From, "2nd Tuesday of next month").unwrap()
+        );
+        assert_eq!(
+            moment(2024, 3, 29),
+            date_matcher(today, From, "last Friday of March 2024").unwrap()
+        );
+        assert_eq!(
+            moment(2023, 3, 31),
+            date_matcher(today, From, "letzter Freitag im Monat").unwrap()
+        );
+        assert_eq!(
+            moment(2023, 3, 26),
+            date_matcher(today, From, "last sunday of the month").unwrap()
+        );
+        assert_eq!(
+            moment(2023, 3, 15),
+            date_matcher(today, From, "3. Mittwoch im März").unwrap()
+        );
+        assert_eq!(
+            moment(2023, 1, 30),
+            date_matcher(today, From, "fünfter Montag im letzten Januar").unwrap()
+        );
+        assert_eq!(
+            moment(2023, 11, 23),
+            date_matcher(today, To, "fourth Thursday of November").unwrap()
+        );
+        let err = date_matcher(today, From, "fifth Monday in February").unwrap_err();
+        assert_eq!(ErrorKind::InvalidDate, err.kind());
+        assert_eq!(Some(0..24), err.span());
+    }
+
+    #[test]
+    fn find_period_boundaries() {
+        // Fri 2023-03-17
+        let today = Doy::from_ymd(2023, 3, 17);
+        assert_eq!(
+            moment(2023, 3, 31),
+            date_matcher(today, To, "end of month").unwrap()
+        );
+        assert_eq!(
+            moment(2023, 3, 31),
+            date_matcher(today, To, "Monatsende").unwrap()
+        );
+        assert_eq!(
+            moment(2023, 3, 31),
+            date_matcher(today, To, "ultimo").unwrap()
+        );
+        assert_eq!(
+            moment(2023, 3, 15),
+            date_matcher(today, To, "medio").unwrap()
+        );
+        assert_eq!(
+            moment(2023, 3, 15),
+            date_matcher(today, To, "Mitte des Monats").unwrap()
+        );
+        assert_eq!(
+            moment(2023, 3, 20),
+            date_matcher(today, From, "Anfang nächster Woche").unwrap()
+        );
+        assert_eq!(
+            moment(2023, 3, 26),
+            date_matcher(today, To, "end of next week").unwrap()
+        );
+        assert_eq!(
+            moment(2023, 3, 16),
+            date_matcher(today, To, "Wochenmitte").unwrap()
+        );
+        assert_eq!(
+            moment(2023, 3, 1),
+            date_matcher(today, From, "beginning of March").unwrap()
+        );
+        assert_eq!(
+            moment(2023, 3, 15),
+            date_matcher(today, From, "mid-March").unwrap()
+        );
+        assert_eq!(
+            moment(2023, 12, 31),
+            date_matcher(today, To, "Jahresende").unwrap()
+        );
+        assert_eq!(
+            moment(2024, 12, 31),
+            date_matcher(today, To, "Ende nächsten Jahres").unwrap()
+        );
+        assert_eq!(
+            moment(2023, 7, 1),
+            date_matcher(today, To, "middle of the year").unwrap()
+        );
+        assert_eq!(
+            moment(2023, 2, 15),
+            date_matcher(today, To, "Quartalsmitte").unwrap()
+        );
+        assert_eq!(
+            moment(2023, 9, 30),
+            date_matcher(today, To, "end of Q3").unwrap()
+        );
+        assert_eq!(
+            moment(2023, 6, 30),
+            date_matcher(today, To, "end of H1 2023").unwrap()
+        );
+        assert_eq!(
+            moment(2023, 4, 1),
+            date_matcher(today, To, "middle of the half-year").unwrap()
+        );
+        assert_eq!(
+            moment(2025, 1, 1),
+            date_matcher(today, To, "middle of this decade").unwrap()
+        );
+        assert_eq!(
+            moment(2024, 1, 1),
+            date_matcher(today, To, "Anfang 2024").unwrap()
+        );
+        assert_eq!(
+            moment(2023, 4, 30),
+            date_matcher(today, To, "Ende April").unwrap()
+        );
+        assert_eq!(
+            interval((2023, 3, 15), (2023, 4, 1)),
+            date_matcher(today, From, "von Monatsmitte bis Monatsende").unwrap()
+        );
+    }
+
+    #[test]
+    fn find_relative_offsets() {
+        // Fri 2023-03-17
+        let today = Doy::from_ymd(2023, 3, 17);
+        assert_eq!(
+            moment(2023, 3, 20),
+            date_matcher(today, From, "in 3 days").unwrap()
+        );
+        assert_eq!(
+            moment(2023, 3, 31),
+            date_matcher(today, From, "in 14 Tagen").unwrap()
+        );
+        assert_eq!(
+            moment(2023, 4, 7),
+            date_matcher(today, From, "in 3 Wochen").unwrap()
+        );
+        assert_eq!(
+            moment(2023, 2, 24),
+            date_matcher(today, From, "3 weeks ago").unwrap()
+        );
+        assert_eq!(
+            moment(2023, 1, 17),
+            date_matcher(today, From, "vor 2 Monaten").unwrap()
+        );
+        assert_eq!(
+            moment(2022, 3, 17),
+            date_matcher(today, From, "vor einem Jahr").unwrap()
+        );
+        assert_eq!(
+            moment(2023, 3, 10),
+            date_matcher(today, From, "a week ago").unwrap()
+        );
+        assert_eq!(
+            moment(2023, 4, 17),
+            date_matcher(today, From, "in a month").unwrap()
+        );
+        assert_eq!(
+            moment(2023, 3, 24),
+            date_matcher(today, From, "heute in einer Woche").unwrap()
+        );
+        assert_eq!(
+            moment(2023, 3, 25),
+            date_matcher(today, From, "morgen in einer Woche").unwrap()
+        );
+        assert_eq!(
+            moment(2023, 3, 22),
+            date_matcher(today, From, "5 days later").unwrap()
+        );
+        assert_eq!(
+            moment(2023, 3, 19),
+            date_matcher(today, From, "2 days from now").unwrap()
+        );
+        assert_eq!(
+            moment(2023, 6, 17),
+            date_matcher(today, From, "in 1 Quartal").unwrap()
+        );
+        assert_eq!(
+            interval((2023, 3, 1), (2023, 4, 1)),
+            date_matcher(today, From, "in March").unwrap()
+        );
+        assert_eq!(
+            interval((2023, 3, 3), (2023, 3, 21)),
+            date_matcher(today, From, "from 2 weeks ago to in 3 days").unwrap()
+        );
+    }
+
+    #[test]
+    fn parse_number_words() {
+        // Fri 2023-03-17
+        let today = Doy::from_ymd(2023, 3, 17);
+        assert_eq!(
+            moment(2023, 2, 24),
+            date_matcher(today, From, "three weeks ago").unwrap()
+        );
+        assert_eq!(
+            moment(2023, 3, 19),
+            date_matcher(today, From, "in zwei Tagen").unwrap()
+        );
+        assert_eq!(
+            moment(2023, 3, 31),
+            date_matcher(today, From, "vierzehn Tage später").unwrap()
+        );
+        assert_eq!(
+            moment(2023, 4, 7),
+            date_matcher(today, From, "in twenty-one days").unwrap()
+        );
+        assert_eq!(
+            moment(2023, 3, 18),
+            date_matcher(today, From, "einen Tag später").unwrap()
+        );
+        assert_eq!(
+            moment(2023, 3, 17),
+            date_matcher(today, From, "in null Tagen").unwrap()
+        );
+        assert_eq!(
+            moment(2023, 4, 7),
+            date_matcher(today, From, "in einundzwanzig Tagen").unwrap()
+        );
+        assert_eq!(
+            moment(2023, 3, 3),
+            date_matcher(today, From, "a fortnight ago").unwrap()
+        );
+        assert_eq!(
+            moment(2023, 9, 17),
+            date_matcher(today, From, "in einem halben Jahr").unwrap()
+        );
+        assert_eq!(
+            moment(2022, 9, 17),
+            date_matcher(today, From, "vor einem halben Jahr").unwrap()
+        );
+        assert_eq!(
+            moment(2023, 9, 17),
+            date_matcher(today, From, "ein halbes Jahr später").unwrap()
+        );
+        assert_eq!(
+            moment(2022, 9, 17),
+            date_matcher(today, From, "half a year ago").unwrap()
+        );
+        assert_eq!(
+            moment(2023, 5, 21),
+            date_matcher(today, From, "twenty-first of May").unwrap()
+        );
+        assert_eq!(
+            moment(2023, 5, 21),
+            date_matcher(today, From, "May the 21st").unwrap()
+        );
+        assert_eq!(
+            moment(2023, 3, 3),
+            date_matcher(today, From, "der dritte März").unwrap()
+        );
+        assert_eq!(
+            moment(2024, 3, 3),
+            date_matcher(today, From, "am dritten März 2024").unwrap()
+        );
+        assert_eq!(
+            moment(2023, 3, 13),
+            date_matcher(today, From, "zweiter Montag im März").unwrap()
+        );
+        assert_eq!(
+            interval((2023, 4, 1), (2023, 7, 1)),
+            date_matcher(today, From, "zweites Quartal").unwrap()
+        );
+        assert_eq!(
+            interval((2024, 1, 1), (2024, 7, 1)),
+            date_matcher(today, From, "first half 2024").unwrap()
+        );
+        assert_eq!(
+            Tempus::Instant(DateTime::new(today, TimeOfDay::new(15, 0))),
+            date_matcher(today, From, "drei Uhr nachmittags").unwrap()
+        );
+        assert_eq!(
+            Tempus::Instant(DateTime::new(today + 1, TimeOfDay::new(10, 30))),
+            date_matcher(today, From, "morgen halb elf").unwrap()
+        );
+        assert!(date_matcher(today, From, "thirty-second of May").is_err());
+        assert!(date_matcher(today, From, "fifth quarter").is_err());
+    }
+
+    #[test]
+    fn find_business_days() {
+        // Fri 2023-03-17
+        let today = Doy::from_ymd(2023, 3, 17);
+        assert_eq!(
+            moment(2023, 3, 22),
+            date_matcher(today, From, "+3 Werktage").unwrap()
+        );
+        assert_eq!(
+            moment(2023, 3, 20),
+            date_matcher(today, From, "next business day").unwrap()
+        );
+        assert_eq!(
+            moment(2023, 3, 20),
+            date_matcher(today, From, "nächster Werktag").unwrap()
+        );
+        assert_eq!(
+            moment(2023, 3, 16),
+            date_matcher(today, From, "previous workday").unwrap()
+        );
+        assert_eq!(
+            moment(2023, 3, 10),
+            date_matcher(today, From, "5 working days ago").unwrap()
+        );
+        assert_eq!(
+            moment(2023, 3, 31),
+            date_matcher(today, From, "in 10 Arbeitstagen").unwrap()
+        );
+        assert_eq!(
+            moment(2023, 3, 20),
+            date_matcher(today, From, "in 3 Tagen").unwrap()
+        );
+    }
+
+    #[test]
+    fn find_rolling_windows() {
+        // Fri 2023-03-17
+        let today = Doy::from_ymd(2023, 3, 17);
+        assert_eq!(
+            interval((2023, 3, 11), (2023, 3, 18)),
+            date_matcher(today, From, "last 7 days").unwrap()
+        );
+        assert_eq!(
+            interval((2023, 3, 11), (2023, 3, 18)),
+            date_matcher(today, To, "in den letzten 7 Tagen").unwrap()
+        );
+        assert_eq!(
+            interval((2023, 3, 4), (2023, 3, 18)),
+            date_matcher(today, From, "letzte 2 Wochen").unwrap()
+        );
+        assert_eq!(
+            interval((2022, 12, 18), (2023, 3, 18)),
+            date_matcher(today, From, "past 3 months").unwrap()
+        );
+        assert_eq!(
+            interval((2022, 12, 18), (2023, 3, 18)),
+            date_matcher(today, From, "die vergangenen drei Monate").unwrap()
+        );
+        assert_eq!(
+            interval((2023, 3, 17), (2023, 3, 27)),
+            date_matcher(today, From, "next 10 days").unwrap()
+        );
+        assert_eq!(
+            interval((2023, 3, 17), (2023, 3, 31)),
+            date_matcher(today, From, "kommende 2 Wochen").unwrap()
+        );
+        assert_eq!(
+            interval((2023, 3, 20), (2023, 3, 27)),
+            date_matcher(today, From, "next week").unwrap()
+        );
+        assert_eq!(
+            interval((2023, 3, 13), (2023, 3, 18)),
+            date_matcher(today, From, "last 5 business days").unwrap()
+        );
+        assert_eq!(
+            interval((2023, 3, 17), (2023, 3, 24)),
+            date_matcher(today, From, "next 5 business days").unwrap()
+        );
+        let saturday = Doy::from_ymd(2023, 3, 18);
+        assert_eq!(
+            interval((2023, 3, 13), (2023, 3, 19)),
+            date_matcher(saturday, From, "die letzten 5 Werktage").unwrap()
+        );
+        assert_eq!(
+            interval((2023, 3, 18), (2023, 3, 25)),
+            date_matcher(saturday, From, "next 5 business days").unwrap()
+        );
+        let err = date_matcher(today, From, "last 0 days").unwrap_err();
+        assert_eq!(ErrorKind::InvalidDate, err.kind());
+    }
+
+    #[test]
+    fn find_weekends() {
+        // Fri 2023-03-17
+        let today = Doy::from_ymd(2023, 3, 17);
+        assert_eq!(
+            interval((2023, 3, 18), (2023, 3, 20)),
+            date_matcher(today, From, "Wochenende").unwrap()
+        );
+        assert_eq!(
+            interval((2023, 3, 18), (2023, 3, 20)),
+            date_matcher(today, From, "this weekend").unwrap()
+        );
+        assert_eq!(
+            interval((2023, 3, 25), (2023, 3, 27)),
+            date_matcher(today, From, "next weekend").unwrap()
+        );
+        assert_eq!(
+            interval((2023, 3, 11), (2023, 3, 13)),
+            date_matcher(today, From, "letztes Wochenende").unwrap()
+        );
+        assert_eq!(
+            interval((2023, 3, 4), (2023, 3, 6)),
+            date_matcher(today, From, "vorletztes Wochenende").unwrap()
+        );
+        assert_eq!(
+            interval((2023, 4, 1), (2023, 4, 3)),
+            date_matcher(today, From, "übernächstes Wochenende").unwrap()
+        );
+        assert_eq!(
+            interval((2023, 3, 13), (2023, 3, 18)),
+            date_matcher(today, From, "diese Arbeitswoche").unwrap()
+        );
+        assert_eq!(
+            interval((2023, 3, 20), (2023, 3, 25)),
+            date_matcher(today, From, "next workweek").unwrap()
+        );
+        // Sun 2023-03-19
+        let sunday = Doy::from_ymd(2023, 3, 19);
+        assert_eq!(
+            interval((2023, 3, 18), (2023, 3, 20)),
+            date_matcher(sunday, From, "am Wochenende").unwrap()
+        );
+        assert_eq!(
+            interval((2023, 3, 20), (2023, 3, 25)),
+            date_matcher(sunday, From, "next work week").unwrap()
+        );
+        assert_eq!(
+            moment(2023, 3, 19),
+            date_matcher(today, From, "Ende der Woche").unwrap()
+        );
+    }
+
+    #[test]
+    fn find_holidays() {
+        // Fri 2023-03-17
+        let today = Doy::from_ymd(2023, 3, 17);
+        assert_eq!(
+            moment(2024, 3, 31),
+            date_matcher(today, From, "Ostern 2024").unwrap()
+        );
+        assert_eq!(
+            moment(2023, 12, 25),
+            date_matcher(today, From, "next Christmas").unwrap()
+        );
+        assert_eq!(
+            moment(2022, 12, 24),
+            date_matcher(today, From, "Heiligabend").unwrap()
+        );
+        assert_eq!(
+            moment(2023, 12, 24),
+            date_matcher(today, To, "Heiligabend").unwrap()
+        );
+        assert_eq!(
+            moment(2023, 4, 9),
+            date_matcher(today, From, "this Easter").unwrap()
+        );
+        assert_eq!(
+            moment(2024, 3, 31),
+            date_matcher(today, From, "übernächste Ostern").unwrap()
+        );
+        assert_eq!(
+            moment(2021, 12, 26),
+            date_matcher(today, From, "vorletzter 2. Weihnachtsfeiertag").unwrap()
+        );
+        assert_eq!(
+            moment(2023, 5, 28),
+            date_matcher(today, To, "Pfingsten").unwrap()
+        );
+        assert_eq!(
+            moment(2023, 4, 7),
+            date_matcher(today, To, "am Karfreitag").unwrap()
+        );
+        assert_eq!(
+            moment(2022, 12, 25),
+            date_matcher(today, From, "am 1. Weihnachtsfeiertag").unwrap()
+        );
+        assert_eq!(
+            moment(2023, 12, 25),
+            date_matcher(today, From, "bis Weihnachten").unwrap()
+        );
+        assert_eq!(
+            moment(2023, 12, 25),
+            date_matcher(today, From, "until Christmas").unwrap()
+        );
+        assert_eq!(
+            moment(2023, 4, 10),
+            date_matcher(today, To, "after Easter").unwrap()
+        );
+        assert_eq!(
+            moment(2022, 4, 18),
+            date_matcher(today, From, "nach Ostern").unwrap()
+        );
+        assert_eq!(
+            moment(2023, 12, 24),
+            date_matcher(today, To, "vor Weihnachten").unwrap()
+        );
+        assert_eq!(
+            moment(2022, 5, 1),
+            date_matcher(today, From, "May Day").unwrap()
+        );
+        assert_eq!(
+            Tempus::Instant(DateTime::new(
+                Doy::from_ymd(2022, 12, 31),
+                TimeOfDay::new(18, 0)
+            )),
+            date_matcher(today, From, "Silvester 18 Uhr").unwrap()
+        );
+        assert_eq!(
+            interval((2023, 12, 1), (2023, 12, 26)),
+            date_matcher(today, From, "vom 1.12. bis Weihnachten").unwrap()
+        );
+        assert_eq!(
+            interval((2023, 5, 1), (2023, 6, 1)),
+            date_matcher(today, From, "May 2023").unwrap()
+        );
+    }
+
+    #[test]
+    fn find_yesterday() {
+        let first_of_march = Doy::from_ymd(2023, 3, 1);
+        assert_eq!(
+            moment(2023, 3, 1),
             date_matcher(first_of_march, To, "heute").unwrap(),
         );
         assert_eq!(
-            Tempus::Moment(Doy::from_ymd(2023, 2, 28)),
+            moment(2023, 2, 28),
             date_matcher(first_of_march, To, "yesterday").unwrap(),
         );
         assert_eq!(
-            Tempus::Moment(Doy::from_ymd(2023, 3, 2)),
+            moment(2023, 3, 2),
             date_matcher(first_of_march, To, "morgen").unwrap(),
         );
     }
 
+    #[test]
+    fn find_more_day_words() {
+        let first_of_march = Doy::from_ymd(2023, 3, 1);
+        assert_eq!(
+            moment(2023, 2, 27),
+            date_matcher(first_of_march, From, "vorgestern").unwrap()
+        );
+        assert_eq!(
+            moment(2023, 2, 27),
+            date_matcher(first_of_march, From, "the day before yesterday").unwrap()
+        );
+        assert_eq!(
+            moment(2023, 2, 27),
+            date_matcher(first_of_march, From, "ehegestern").unwrap()
+        );
+        assert_eq!(
+            moment(2023, 3, 3),
+            date_matcher(first_of_march, From, "übermorgen").unwrap()
+        );
+        assert_eq!(
+            moment(2023, 3, 3),
+            date_matcher(first_of_march, From, "day after tomorrow").unwrap()
+        );
+        assert_eq!(
+            moment(2023, 3, 10),
+            date_matcher(first_of_march, From, "übermorgen in einer Woche").unwrap()
+        );
+        assert_eq!(
+            Tempus::Instant(DateTime::new(
+                Doy::from_ymd(2023, 3, 3),
+                TimeOfDay::new(14, 0)
+            )),
+            date_matcher(first_of_march, From, "übermorgen 14:00").unwrap()
+        );
+        assert_eq!(
+            moment(2023, 3, 1),
+            date_matcher(first_of_march, From, "jetzt").unwrap()
+        );
+        assert_eq!(
+            Tempus::Instant(DateTime::new(first_of_march, TimeOfDay::new(18, 0))),
+            date_matcher(first_of_march, From, "tonight").unwrap()
+        );
+        assert_eq!(
+            Tempus::Instant(DateTime::new(first_of_march, TimeOfDay::new(20, 0))),
+            date_matcher(first_of_march, From, "tonight at 8").unwrap()
+        );
+        assert_eq!(
+            Tempus::Instant(DateTime::new(first_of_march, TimeOfDay::new(22, 0))),
+            date_matcher(first_of_march, To, "heut Abend").unwrap()
+        );
+    }
+
     #[test]
     fn adding_times() {
         // Fri 2023-03-17
         let today = Doy::from_ymd(2023, 3, 17);
         assert_eq!(
-            Tempus::Moment(Doy::from_ymd(2023, 3, 22)),
+            moment(2023, 3, 22),
             date_matcher(today, From, "+5 Tage").unwrap(),
         );
         let today = Doy::from_ymd(2023, 3, 17);
         assert_eq!(
-            Tempus::Moment(Doy::from_ymd(2022, 3, 17)),
+            moment(2022, 3, 17),
             date_matcher(today, From, "-1 year").unwrap(),
         );
         assert_eq!(
-            Tempus::Moment(Doy::from_ymd(2022, 2, 17)),
+            moment(2022, 2, 17),
             date_matcher(today, From, "-13 month").unwrap(),
         );
+        assert_eq!(
+            moment(2023, 3, 12),
+            date_matcher(today, From, "-5 days").unwrap(),
+        );
+        assert_eq!(
+            moment(2023, 3, 31),
+            date_matcher(today, From, "+2 weeks").unwrap(),
+        );
+        assert_eq!(
+            moment(2023, 2, 24),
+            date_matcher(today, From, "-3 Wochen").unwrap(),
+        );
+        assert_eq!(
+            moment(2023, 5, 17),
+            date_matcher(today, From, "+2 Monate").unwrap(),
+        );
+        assert_eq!(
+            moment(2023, 9, 17),
+            date_matcher(today, From, "+2 quarters").unwrap(),
+        );
+        assert_eq!(
+            moment(2022, 12, 17),
+            date_matcher(today, From, "-1 Quartal").unwrap(),
+        );
+        assert_eq!(
+            moment(2025, 3, 17),
+            date_matcher(today, From, "+2 Jahre").unwrap(),
+        );
+        assert_eq!(
+            Tempus::Moment(today),
+            date_matcher(today, From, "+0 weeks").unwrap(),
+        );
     }
 
     #[test]
@@ -319,40 +1988,40 @@ mod should {
         // Fri 2023-03-17
         let today = Doy::from_ymd(2023, 3, 17);
         assert_eq!(
-            Tempus::Moment(Doy::from_ymd(2023, 1, 22)),
+            moment(2023, 1, 22),
             date_matcher(today, From, "22.01.2023").unwrap(),
         );
         assert_eq!(
-            Tempus::Moment(Doy::from_ymd(2023, 1, 22)),
+            moment(2023, 1, 22),
             date_matcher(today, From, "22.1.23").unwrap(),
         );
         assert_eq!(
-            Tempus::Moment(Doy::from_ymd(2023, 1, 22)),
+            moment(2023, 1, 22),
             date_matcher(today, From, "22.1.").unwrap(),
         );
         assert_eq!(
-            Tempus::Moment(Doy::from_ymd(2023, 3, 16)),
+            moment(2023, 3, 16),
             date_matcher(today, From, "3/16/2023").unwrap(),
         );
         assert_eq!(
-            Tempus::Moment(Doy::from_ymd(2023, 3, 16)),
+            moment(2023, 3, 16),
             date_matcher(today, From, "2023-03-16").unwrap(),
         );
         assert_eq!(
-            Tempus::Moment(Doy::from_ymd(2023, 3, 16)),
+            moment(2023, 3, 16),
             date_matcher(today, From, "    23-03-16  ").unwrap(),
         );
 
         assert_eq!(
-            Tempus::Moment(Doy::from_ymd(2023, 3, 16)),
+            moment(2023, 3, 16),
             date_matcher(today, From, "16. Mär 2023").unwrap(),
         );
         assert_eq!(
-            Tempus::Moment(Doy::from_ymd(2023, 3, 16)),
+            moment(2023, 3, 16),
             date_matcher(today, From, "16. März 2023").unwrap(),
         );
         assert_eq!(
-            Tempus::Moment(Doy::from_ymd(2023, 3, 16)),
+            moment(2023, 3, 16),
             date_matcher(today, From, "March 16th 2023").unwrap(),
         );
     }
@@ -362,17 +2031,305 @@ mod should {
         let today = Doy::from_ymd(2023, 3, 17);
 
         assert_eq!(
-            Tempus::Interval(Doy::from_ymd(2023, 3, 27), Doy::from_ymd(2023, 4, 3)),
+            interval((2023, 3, 27), (2023, 4, 3)),
             date_matcher(today, From, "2023-W13").unwrap(),
         );
         assert_eq!(
-            Tempus::Interval(Doy::from_ymd(2020, 12, 21), Doy::from_ymd(2020, 12, 28)),
+            interval((2020, 12, 21), (2020, 12, 28)),
             date_matcher(today, From, "Woche 2020-52").unwrap(),
         );
 
         assert_eq!(
-            Tempus::Interval(Doy::from_ymd(2020, 12, 21), Doy::from_ymd(2020, 12, 28)),
+            interval((2020, 12, 21), (2020, 12, 28)),
             date_matcher(today, From, "KW 20/52").unwrap(),
         );
     }
+
+    #[test]
+    fn parse_time() {
+        // Fri 2023-03-17
+        let today = Doy::from_ymd(2023, 3, 17);
+        assert_eq!(
+            at(today + 1, 14, 30),
+            date_matcher(today, From, "tomorrow 14:30").unwrap(),
+        );
+        assert_eq!(
+            at(Doy::from_ymd(2023, 3, 24), 9, 0),
+            date_matcher(today, To, "Fr 9 Uhr").unwrap(),
+        );
+        assert_eq!(
+            at(Doy::from_ymd(2023, 3, 20), 15, 0),
+            date_matcher(today, From, "next monday at 3pm").unwrap(),
+        );
+        assert_eq!(
+            at(today + 1, 15, 0),
+            date_matcher(today, From, "3 pm tomorrow").unwrap(),
+        );
+        assert_eq!(
+            at(today, 12, 0),
+            date_matcher(today, From, "heute Mittag").unwrap(),
+        );
+        assert_eq!(
+            at(today, 14, 30),
+            date_matcher(today, From, "14.30 Uhr").unwrap(),
+        );
+        assert_eq!(
+            Tempus::Instant(DateTime::new(today, TimeOfDay::hms(8, 5, 30))),
+            date_matcher(today, From, "8:05:30").unwrap(),
+        );
+        assert_eq!(
+            at(Doy::from_ymd(2023, 3, 16), 14, 30),
+            date_matcher(today, From, "2023-03-16T14:30").unwrap(),
+        );
+        assert_eq!(
+            at(Doy::from_ymd(2023, 1, 22), 0, 15),
+            date_matcher(today, From, "22.1. 12:15 a.m.").unwrap(),
+        );
+        assert_eq!(
+            at(today, 12, 30),
+            date_matcher(today, From, "12:30pm").unwrap(),
+        );
+        assert!(date_matcher(today, From, "13pm").is_err());
+        assert!(date_matcher(today, From, "25:00").is_err());
+    }
+
+    #[test]
+    fn parse_spoken_time() {
+        // Fri 2023-03-17
+        let today = Doy::from_ymd(2023, 3, 17);
+        assert_eq!(
+            at(today, 2, 30),
+            date_matcher(today, From, "halb drei").unwrap(),
+        );
+        assert_eq!(
+            at(today + 1, 14, 30),
+            date_matcher(today, From, "morgen Nachmittag halb drei").unwrap(),
+        );
+        assert_eq!(
+            at(today, 20, 0),
+            date_matcher(today, From, "heute Abend um 8").unwrap(),
+        );
+        assert_eq!(
+            at(today + 1, 3, 0),
+            date_matcher(today, From, "morgen um drei").unwrap(),
+        );
+        assert_eq!(
+            at(today, 1, 0),
+            date_matcher(today, From, "at one").unwrap(),
+        );
+        assert_eq!(
+            at(today, 20, 0),
+            date_matcher(today, From, "8 Uhr abends").unwrap(),
+        );
+        assert_eq!(
+            at(today, 3, 15),
+            date_matcher(today, From, "quarter past three").unwrap(),
+        );
+        assert_eq!(
+            at(today, 11, 45),
+            date_matcher(today, From, "viertel vor 12").unwrap(),
+        );
+        assert_eq!(
+            at(today - 1, 23, 30),
+            date_matcher(today, From, "halb null").unwrap(),
+        );
+        assert_eq!(
+            at(today, 23, 45),
+            date_matcher(today, From, "morgen viertel vor null").unwrap(),
+        );
+        let err = date_matcher(today, From, "vierundzwanzig Uhr").unwrap_err();
+        assert_eq!(ErrorKind::InvalidDate, err.kind());
+        assert!(date_matcher(today, From, "24 Uhr").is_err());
+        assert_eq!(
+            at(today + 1, 7, 0),
+            date_matcher(today, From, "tomorrow 7 o'clock").unwrap(),
+        );
+    }
+
+    #[test]
+    fn parse_day_parts() {
+        // Fri 2023-03-17
+        let today = Doy::from_ymd(2023, 3, 17);
+        assert_eq!(
+            at(today + 1, 6, 0),
+            date_matcher(today, From, "morgen früh").unwrap(),
+        );
+        assert_eq!(
+            at(today + 1, 12, 0),
+            date_matcher(today, To, "tomorrow morning").unwrap(),
+        );
+        assert_eq!(
+            at(today, 6, 0),
+            date_matcher(today, From, "heute morgen").unwrap(),
+        );
+        assert_eq!(
+            at(Doy::from_ymd(2023, 3, 13), 18, 0),
+            date_matcher(today, From, "Montag Abend").unwrap(),
+        );
+        assert_eq!(
+            at(today + 1, 6, 0),
+            date_matcher(today, To, "heute Nacht").unwrap(),
+        );
+        assert_eq!(
+            at(today + 1, 0, 0),
+            date_matcher(today, To, "midnight").unwrap(),
+        );
+        assert_eq!(
+            at(today + 1, 0, 0),
+            date_matcher(today, From, "midnight").unwrap(),
+        );
+        assert_eq!(
+            at(today + 1, 0, 0),
+            date_matcher(today, To, "tonight at midnight").unwrap(),
+        );
+        assert_eq!(
+            at(today + 1, 0, 0),
+            date_matcher(today, From, "heute um Mitternacht").unwrap(),
+        );
+        assert_eq!(
+            at(today, 12, 0),
+            date_matcher(today, From, "at noon").unwrap(),
+        );
+        assert_eq!(
+            Tempus::Moment(today + 1),
+            date_matcher(today, From, "morgen").unwrap(),
+        );
+    }
+
+    #[test]
+    fn parse_range() {
+        // Fri 2023-03-17
+        let today = Doy::from_ymd(2023, 3, 17);
+        assert_eq!(
+            interval((2023, 3, 1), (2023, 3, 16)),
+            date_matcher(today, From, "from 1.3. to 15.3.").unwrap(),
+        );
+        assert_eq!(
+            interval((2023, 3, 13), (2023, 3, 18)),
+            date_matcher(today, To, "vom letzten Montag bis heute").unwrap(),
+        );
+        assert_eq!(
+            interval((2023, 3, 1), (2023, 4, 1)),
+            date_matcher(today, From, "2023-03-01..2023-03-31").unwrap(),
+        );
+        assert_eq!(
+            interval((2023, 3, 1), (2023, 3, 16)),
+            date_matcher(today, From, "Mar 1 – Mar 15").unwrap(),
+        );
+        assert_eq!(
+            interval((2023, 3, 1), (2023, 3, 16)),
+            date_matcher(today, From, "1.3. - 15.3.").unwrap(),
+        );
+        assert_eq!(
+            interval((2023, 3, 13), (2023, 3, 25)),
+            date_matcher(today, From, "zwischen Montag und nächstem Freitag").unwrap(),
+        );
+        assert_eq!(
+            interval((2023, 3, 6), (2023, 3, 20)),
+            date_matcher(today, From, "KW 23/10 - KW 23/11").unwrap(),
+        );
+        assert!(date_matcher(today, From, "from 15.3.2023 to 1.3.2023").is_err());
+        assert!(date_matcher(today, From, "from 9:00 to 17:00").is_err());
+    }
+
+    #[test]
+    fn resolve_range_end_after_start() {
+        // Fri 2023-03-17
+        let today = Doy::from_ymd(2023, 3, 17);
+        assert_eq!(
+            interval((2022, 5, 1), (2022, 7, 1)),
+            date_matcher(today, From, "Mai bis Juni").unwrap(),
+        );
+        assert_eq!(
+            interval((2022, 4, 17), (2022, 6, 6)),
+            date_matcher(today, From, "Ostern bis Pfingsten").unwrap(),
+        );
+        assert_eq!(
+            interval((2022, 12, 25), (2023, 1, 1)),
+            date_matcher(today, From, "Weihnachten bis Silvester").unwrap(),
+        );
+        assert_eq!(
+            interval((2023, 3, 13), (2023, 3, 18)),
+            date_matcher(today, From, "Montag bis Freitag").unwrap(),
+        );
+        assert_eq!(
+            interval((2023, 3, 10), (2023, 3, 14)),
+            date_matcher(today, From, "Freitag bis Montag").unwrap(),
+        );
+        assert_eq!(
+            interval((2023, 12, 20), (2024, 1, 6)),
+            date_matcher(today, From, "Dec 20 - Jan 5").unwrap(),
+        );
+        assert_eq!(
+            interval((2023, 3, 15), (2024, 3, 2)),
+            date_matcher(today, From, "from 15.3. to 1.3.").unwrap(),
+        );
+    }
+
+    #[test]
+    fn reject_impossible_dates_strictly() {
+        // Fri 2023-03-17
+        let today = Doy::from_ymd(2023, 3, 17);
+        assert_eq!(
+            moment(2023, 3, 3),
+            date_matcher(today, From, "31.2.23").unwrap(),
+        );
+        assert!(date_matcher_strict(today, From, "31.2.23").is_err());
+        assert!(date_matcher_strict(today, From, "2023-02-29").is_err());
+        assert!(date_matcher_strict(today, From, "Feb 30th 2024").is_err());
+        assert!(date_matcher_strict(today, From, "00.1.").is_err());
+        assert!(date_matcher_strict(today, From, "KW 23/53").is_err());
+        assert!(date_matcher_strict(today, From, "from 1.3. to 31.4.").is_err());
+        assert_eq!(
+            moment(2024, 2, 29),
+            date_matcher_strict(today, From, "29.2.24").unwrap(),
+        );
+        assert_eq!(
+            interval((2020, 12, 28), (2021, 1, 4)),
+            date_matcher_strict(today, From, "2020-W53").unwrap(),
+        );
+    }
+
+    #[test]
+    fn report_errors() {
+        // Fri 2023-03-17
+        let today = Doy::from_ymd(2023, 3, 17);
+        let err = date_matcher(today, From, "tomorow 14:30").unwrap_err();
+        assert_eq!(ErrorKind::Unrecognized, err.kind());
+        assert_eq!(Some(0..7), err.span());
+        assert!(!err.expected().is_empty());
+
+        let err = date_matcher(today, From, "morgen 14:30 Uhr x").unwrap_err();
+        assert_eq!(ErrorKind::Unrecognized, err.kind());
+        assert_eq!(Some(17..18), err.span());
+
+        let err = date_matcher(today, From, "morgen\n14:30 Uhr x").unwrap_err();
+        assert_eq!(Some(17..18), err.span());
+
+        let err = date_matcher_strict(today, From, "vom 1.3. bis 31.4.").unwrap_err();
+        assert_eq!(ErrorKind::InvalidDate, err.kind());
+        assert_eq!(Some(13..18), err.span());
+
+        let err = date_matcher_strict(today, From, "  KW 23/53").unwrap_err();
+        assert_eq!(ErrorKind::WeekOutOfRange, err.kind());
+        assert_eq!(Some(2..10), err.span());
+
+        let err = date_matcher(today, From, "+99999999999 days").unwrap_err();
+        assert_eq!(ErrorKind::Overflow, err.kind());
+        assert_eq!(Some(0..12), err.span());
+
+        let err = date_matcher(today, From, "+2147483647 years").unwrap_err();
+        assert_eq!(ErrorKind::Overflow, err.kind());
+
+        let err = date_matcher(today, From, "+2000000000 Werktage").unwrap_err();
+        assert_eq!(ErrorKind::Overflow, err.kind());
+
+        let err = date_matcher(today, From, "from 15.3.2023 to 1.3.2023").unwrap_err();
+        assert_eq!(ErrorKind::InvalidDate, err.kind());
+        assert_eq!(Some(0..26), err.span());
+
+        let err = date_matcher(today, From, "from 9:00 to 17:00").unwrap_err();
+        assert_eq!(ErrorKind::InvalidDate, err.kind());
+        assert_eq!(Some(5..10), err.span());
+    }
 }
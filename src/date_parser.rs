@@ -0,0 +1,381 @@
+use crate::date_matcher::{matcher, Context, Direction};
+use crate::day_of_week::DayOfWeek;
+use crate::doy::{Doy, EndOfMonth, Tempus};
+use crate::holiday::HolidayCalendar;
+use crate::TimeWarpError;
+
+/// National notations of numeric dates. ISO-dates `yyyy-mm-dd` are always accepted.
+#[derive(Debug, Copy, Clone, Eq, PartialEq)]
+pub enum Locale {
+    /// German `d.m.y`
+    De,
+    /// US-English `m/d/y`
+    EnUs,
+    /// British-English `d/m/y`
+    EnGb,
+}
+
+/// The year of a date given without one, like "22.1." or "Mar 1".
+#[derive(Debug, Default, Copy, Clone, Eq, PartialEq)]
+pub enum YearInference {
+    /// Always the year of the reference date.
+    #[default]
+    CurrentYear,
+    /// The latest occurrence on or before the reference date.
+    Past,
+    /// The next occurrence on or after the reference date.
+    Future,
+    /// The occurrence closest to the reference date.
+    Nearest,
+}
+
+/// A configured, reusable parser. It is immutable after `DateParserBuilder::build`, so it can be
+/// shared between threads.
+///
+/// ```rust
+/// use timewarp::Direction::*;
+/// use timewarp::{DateParser, Doy, Locale, Tempus, YearInference};
+///
+/// let parser = DateParser::builder()
+///     .today(Doy::from_ymd(2023, 3, 17))
+///     .locales(&[Locale::De, Locale::EnGb])
+///     .year_inference(YearInference::Future)
+///     .build();
+/// assert_eq!(
+///     parser.parse("22.1.").unwrap(),
+///     Tempus::Moment(Doy::from_ymd(2024, 1, 22))
+/// );
+/// assert_eq!(
+///     parser.parse("16/3/2023").unwrap(),
+///     Tempus::Moment(Doy::from_ymd(2023, 3, 16))
+/// );
+/// ```
+#[derive(Debug, Clone, Eq, PartialEq)]
+pub struct DateParser {
+    pub(crate) today: Option<Doy>,
+    pub(crate) direction: Direction,
+    pub(crate) locales: Vec<Locale>,
+    pub(crate) pivot: Option<i32>,
+    pub(crate) week_start: DayOfWeek,
+    pub(crate) strict: bool,
+    pub(crate) year_inference: YearInference,
+    pub(crate) end_of_month: EndOfMonth,
+    pub(crate) holidays: Option<HolidayCalendar>,
+}
+
+impl Default for DateParser {
+    fn default() -> Self {
+        Self {
+            today: None,
+            direction: Direction::From,
+            locales: vec![Locale::De, Locale::EnUs],
+            pivot: None,
+            week_start: DayOfWeek::Mon,
+            strict: false,
+            year_inference: YearInference::CurrentYear,
+            end_of_month: EndOfMonth::Clamp,
+            holidays: None,
+        }
+    }
+}
+
+impl DateParser {
+    pub fn builder() -> DateParserBuilder {
+        DateParserBuilder::default()
+    }
+
+    /// Parses `text` in the default `Direction`.
+    ///
+    /// # Errors
+    /// if `text` is not understood or does not resolve into a valid date.
+    pub fn parse(&self, text: &str) -> Result<Tempus, TimeWarpError> {
+        self.parse_as(self.direction, text)
+    }
+
+    /// Parses `text` in the given `direction`.
+    ///
+    /// # Errors
+    /// if `text` is not understood or does not resolve into a valid date.
+    pub fn parse_as(&self, direction: Direction, text: &str) -> Result<Tempus, TimeWarpError> {
+        let cx = Context {
+            today: self.today.unwrap_or_else(Doy::today),
+            direction,
+            parser: self,
+            after: None,
+        };
+        matcher(&cx, text)
+    }
+}
+
+/// Builds a `DateParser`, unset options keep the defaults of `date_matcher`.
+#[must_use]
+#[derive(Debug, Default, Clone)]
+pub struct DateParserBuilder {
+    parser: DateParser,
+}
+
+impl DateParserBuilder {
+    /// The reference date relative expressions are resolved against. Without it, every call
+    /// uses the current day.
+    pub fn today(mut self, today: Doy) -> Self {
+        self.parser.today = Some(today);
+        self
+    }
+
+    /// The `Direction` used by `DateParser::parse`, default is `Direction::From`.
+    pub fn direction(mut self, direction: Direction) -> Self {
+        self.parser.direction = direction;
+        self
+    }
+
+    /// The accepted national date notations, default is `De` and `EnUs`. If both `EnUs` and
+    /// `EnGb` are given, a date like "3/4/2023" is rejected as ambiguous.
+    pub fn locales(mut self, locales: &[Locale]) -> Self {
+        self.parser.locales = locales.to_vec();
+        self
+    }
+
+    /// Two-digit years are placed into the century starting at `pivot`: with 1950, "49" is 2049
+    /// and "50" is 1950. Default is a window of ±50 years around the reference date.
+    pub fn two_digit_year_pivot(mut self, pivot: i32) -> Self {
+        self.parser.pivot = Some(pivot);
+        self
+    }
+
+    /// The first day of a week, default is Monday (iso 8601).
+    pub fn week_start(mut self, week_start: DayOfWeek) -> Self {
+        self.parser.week_start = week_start;
+        self
+    }
+
+    /// Rejects impossible calendar dates like "31.2.23" instead of rolling them over.
+    pub fn strict(mut self, strict: bool) -> Self {
+        self.parser.strict = strict;
+        self
+    }
+
+    /// The year of dates given without one, default is `YearInference::CurrentYear`.
+    pub fn year_inference(mut self, year_inference: YearInference) -> Self {
+        self.parser.year_inference = year_inference;
+        self
+    }
+
+    /// How "+1 month" or "-1 year" handle days beyond the end of the target month, default is
+    /// `EndOfMonth::Clamp`.
+    pub fn end_of_month(mut self, end_of_month: EndOfMonth) -> Self {
+        self.parser.end_of_month = end_of_month;
+        self
+    }
+
+    /// The holidays skipped by "+3 Werktage" or "next business day" besides weekends, default is
+    /// none.
+    pub fn holidays(mut self, holidays: HolidayCalendar) -> Self {
+        self.parser.holidays = Some(holidays);
+        self
+    }
+
+    pub fn build(self) -> DateParser {
+        self.parser
+    }
+}
+
+#[cfg(test)]
+mod should {
+    use crate::date_parser::{DateParser, Locale, YearInference};
+    use crate::Direction::{From, To};
+    use crate::{DateTime, DayOfWeek, Doy, EndOfMonth, ErrorKind, HolidayCalendar, Tempus};
+    use std::sync::Arc;
+    use std::thread;
+
+    fn moment(y: i32, m: i32, d: i32) -> Tempus {
+        Tempus::Moment(Doy::from_ymd(y, m, d))
+    }
+
+    #[test]
+    fn share_between_threads() {
+        let parser = Arc::new(
+            DateParser::builder()
+                .today(Doy::from_ymd(2023, 3, 17))
+                .build(),
+        );
+        let handles: Vec<_> = (1..=4)
+            .map(|day| {
+                let parser = Arc::clone(&parser);
+                thread::spawn(move || parser.parse(&format!("{day}.1.")).unwrap())
+            })
+            .collect();
+        for (day, handle) in (1..).zip(handles) {
+            assert_eq!(moment(2023, 1, day), handle.join().unwrap());
+        }
+    }
+
+    #[test]
+    fn use_default_direction() {
+        let parser = DateParser::builder()
+            .today(Doy::from_ymd(2023, 3, 17))
+            .direction(To)
+            .build();
+        assert_eq!(moment(2023, 3, 21), parser.parse("tuesday").unwrap());
+        assert_eq!(
+            moment(2023, 3, 14),
+            parser.parse_as(From, "tuesday").unwrap()
+        );
+    }
+
+    #[test]
+    fn start_weeks_on_week_start() {
+        // Fri 2023-03-17
+        let parser = DateParser::builder()
+            .today(Doy::from_ymd(2023, 3, 17))
+            .week_start(DayOfWeek::Sun)
+            .build();
+        assert_eq!(
+            Tempus::Interval(Doy::from_ymd(2023, 3, 12), Doy::from_ymd(2023, 3, 19)),
+            parser.parse("this week").unwrap()
+        );
+    }
+
+    #[test]
+    fn add_months_by_policy() {
+        let parser = |end_of_month| {
+            DateParser::builder()
+                .today(Doy::from_ymd(2023, 1, 31))
+                .end_of_month(end_of_month)
+                .build()
+        };
+        let clamp = parser(EndOfMonth::Clamp);
+        assert_eq!(moment(2023, 2, 28), clamp.parse("+1 month").unwrap());
+        assert_eq!(moment(2023, 3, 31), clamp.parse("+2 months").unwrap());
+        let overflow = parser(EndOfMonth::Overflow);
+        assert_eq!(moment(2023, 3, 3), overflow.parse("+1 Monat").unwrap());
+        let sticky = parser(EndOfMonth::Sticky);
+        assert_eq!(moment(2023, 2, 28), sticky.parse("+1 month").unwrap());
+        assert_eq!(moment(2022, 1, 31), sticky.parse("-1 year").unwrap());
+        let sticky = DateParser::builder()
+            .today(Doy::from_ymd(2023, 2, 28))
+            .end_of_month(EndOfMonth::Sticky)
+            .build();
+        assert_eq!(moment(2023, 3, 31), sticky.parse("+1 month").unwrap());
+        assert_eq!(moment(2024, 2, 29), sticky.parse("+1 Jahr").unwrap());
+    }
+
+    #[test]
+    fn respect_locales() {
+        let today = Doy::from_ymd(2023, 3, 17);
+        let us = DateParser::builder().today(today).build();
+        assert_eq!(moment(2023, 3, 4), us.parse("3/4/2023").unwrap());
+        let gb = DateParser::builder()
+            .today(today)
+            .locales(&[Locale::EnGb])
+            .build();
+        assert_eq!(moment(2023, 4, 3), gb.parse("3/4/2023").unwrap());
+        assert!(gb.parse("3.4.2023").is_err());
+        assert_eq!(moment(2023, 3, 4), gb.parse("2023-03-04").unwrap());
+        let both = DateParser::builder()
+            .today(today)
+            .locales(&[Locale::EnUs, Locale::EnGb])
+            .build();
+        let err = both.parse("3/4/2023").unwrap_err();
+        assert_eq!(ErrorKind::Ambiguous, err.kind());
+        assert_eq!(Some(0..8), err.span());
+        assert_eq!(moment(2023, 3, 16), both.parse("16/3/2023").unwrap());
+        assert_eq!(moment(2023, 3, 16), both.parse("3/16/2023").unwrap());
+        assert_eq!(moment(2023, 3, 3), both.parse("3/3/2023").unwrap());
+    }
+
+    #[test]
+    fn place_two_digit_years() {
+        let today = Doy::from_ymd(2023, 3, 17);
+        let parser = DateParser::builder()
+            .today(today)
+            .two_digit_year_pivot(1950)
+            .build();
+        assert_eq!(moment(2049, 1, 22), parser.parse("22.1.49").unwrap());
+        assert_eq!(moment(1950, 1, 22), parser.parse("22.1.50").unwrap());
+        assert_eq!(moment(1989, 1, 22), parser.parse("22.1.1989").unwrap());
+        let sliding = DateParser::builder().today(today).build();
+        assert_eq!(moment(1989, 1, 22), sliding.parse("22.1.89").unwrap());
+    }
+
+    #[test]
+    fn infer_missing_years() {
+        // Fri 2023-03-17
+        let parser = |year_inference| {
+            DateParser::builder()
+                .today(Doy::from_ymd(2023, 3, 17))
+                .year_inference(year_inference)
+                .build()
+        };
+        let current = parser(YearInference::CurrentYear);
+        assert_eq!(moment(2023, 12, 1), current.parse("1.12.").unwrap());
+        let past = parser(YearInference::Past);
+        assert_eq!(moment(2022, 12, 1), past.parse("1.12.").unwrap());
+        assert_eq!(moment(2023, 3, 17), past.parse("17.3.").unwrap());
+        let future = parser(YearInference::Future);
+        assert_eq!(moment(2024, 1, 1), future.parse("Jan 1").unwrap());
+        assert_eq!(moment(2023, 3, 17), future.parse("17.3.").unwrap());
+        let nearest = parser(YearInference::Nearest);
+        assert_eq!(moment(2022, 12, 1), nearest.parse("1.12.").unwrap());
+        assert_eq!(moment(2023, 8, 1), nearest.parse("1.8.").unwrap());
+        assert_eq!(
+            Tempus::Interval(Doy::from_ymd(2022, 12, 1), Doy::from_ymd(2023, 1, 16)),
+            nearest.parse("vom 1.12. bis 15.1.").unwrap()
+        );
+    }
+
+    #[test]
+    fn skip_holidays_in_business_days() {
+        // Thu 2024-03-28, before Good Friday and Easter Monday
+        let today = Doy::from_ymd(2024, 3, 28);
+        let parser = DateParser::builder()
+            .today(today)
+            .holidays(HolidayCalendar::De(None))
+            .build();
+        assert_eq!(
+            moment(2024, 4, 2),
+            parser.parse("nächster Werktag").unwrap()
+        );
+        assert_eq!(moment(2024, 4, 4), parser.parse("+3 Werktage").unwrap());
+        let weekends_only = DateParser::builder().today(today).build();
+        assert_eq!(
+            moment(2024, 4, 2),
+            weekends_only.parse("+3 Werktage").unwrap()
+        );
+    }
+
+    #[test]
+    fn resolve_now_without_reference_date() {
+        let before = DateTime::now();
+        let now = DateParser::default().parse("now").unwrap();
+        let after = DateTime::now();
+        match now {
+            Tempus::Instant(now) => assert!(before <= now && now <= after),
+            _ => panic!("Expected an instant, found {now:?}"),
+        }
+    }
+
+    #[test]
+    fn resolve_now_in_ranges_to_today() {
+        let parser = DateParser::default();
+        let today = Doy::today();
+        assert_eq!(
+            Tempus::Interval(today - 1, today + 1),
+            parser.parse("seit gestern bis jetzt").unwrap()
+        );
+        assert_eq!(
+            Tempus::Interval(today - 1, today + 1),
+            parser.parse("from yesterday to now").unwrap()
+        );
+    }
+
+    #[test]
+    fn reject_strictly() {
+        let parser = DateParser::builder()
+            .today(Doy::from_ymd(2023, 3, 17))
+            .strict(true)
+            .year_inference(YearInference::Future)
+            .build();
+        assert_eq!(moment(2024, 2, 29), parser.parse("29.2.").unwrap());
+        assert!(parser.parse("30.2.").is_err());
+    }
+}
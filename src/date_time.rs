@@ -0,0 +1,114 @@
+use crate::doy::{millis_since_epoch, Doy};
+use crate::time_of_day::TimeOfDay;
+use std::fmt::{Display, Formatter};
+use std::time::SystemTime;
+
+/// A `Doy` combined with a `TimeOfDay`.
+#[must_use]
+#[derive(Eq, PartialEq, Ord, PartialOrd, Hash, Copy, Clone, Debug)]
+pub struct DateTime {
+    pub date: Doy,
+    pub time: TimeOfDay,
+}
+
+impl DateTime {
+    /// Creates a `DateTime` at the given `time` of the `date`.
+    pub fn new(date: Doy, time: TimeOfDay) -> Self {
+        Self { date, time }
+    }
+
+    /// The current date and time (UTC).
+    pub fn now() -> Self {
+        Self::from(SystemTime::now())
+    }
+
+    /// Creates a `DateTime` at midnight of the `date`.
+    pub fn start_of_day(date: Doy) -> Self {
+        Self::new(date, TimeOfDay::MIDNIGHT)
+    }
+
+    /// Adds (or subtracts) `seconds`, moving over to the next (or previous) days.
+    pub fn add_seconds(self, seconds: i32) -> Self {
+        let total = self.time.seconds() + seconds;
+        let days = total.div_euclid(24 * 3600);
+        Self::new(self.date + days, TimeOfDay::from_seconds(total))
+    }
+
+    /// converts milliseconds from POSIX time or Epoch time to DateTime (UTC).
+    pub fn from_millis(millis: i64) -> Self {
+        let second = millis.div_euclid(Doy::SECOND as i64).rem_euclid(24 * 3600);
+        Self::new(
+            Doy::from_millis(millis),
+            TimeOfDay::from_seconds(second as i32),
+        )
+    }
+
+    /// returns the milliseconds since Epoch of this date-time (UTC).
+    pub fn to_millis(self) -> i64 {
+        self.date.to_millis() + i64::from(self.time.seconds()) * Doy::SECOND as i64
+    }
+
+    /// returns this date-time in iso-format `yyyy-mm-ddThh:mm:ss`.
+    pub fn as_iso_date_time(self) -> String {
+        format!("{self:#}")
+    }
+}
+
+impl From<Doy> for DateTime {
+    fn from(date: Doy) -> Self {
+        Self::start_of_day(date)
+    }
+}
+
+impl From<SystemTime> for DateTime {
+    fn from(time: SystemTime) -> Self {
+        Self::from_millis(millis_since_epoch(time))
+    }
+}
+
+impl Display for DateTime {
+    fn fmt(&self, f: &mut Formatter<'_>) -> Result<(), std::fmt::Error> {
+        if f.alternate() {
+            write!(f, "{:#}T{:#}", self.date, self.time)
+        } else {
+            write!(f, "{}T{}", self.date, self.time)
+        }
+    }
+}
+
+#[cfg(test)]
+mod should {
+    use crate::date_time::DateTime;
+    use crate::doy::Doy;
+    use crate::time_of_day::TimeOfDay;
+
+    #[test]
+    fn add_seconds_over_midnight() {
+        let dt = DateTime::new(Doy::from_ymd(2023, 12, 31), TimeOfDay::new(23, 30));
+        assert_eq!(
+            DateTime::new(Doy::from_ymd(2024, 1, 1), TimeOfDay::new(0, 15)),
+            dt.add_seconds(45 * 60)
+        );
+        assert_eq!(
+            DateTime::new(Doy::from_ymd(2023, 12, 30), TimeOfDay::new(23, 30)),
+            dt.add_seconds(-24 * 3600)
+        );
+    }
+
+    #[test]
+    fn convert_millis() {
+        let dt = DateTime::new(Doy::from_ymd(2023, 3, 17), TimeOfDay::hms(20, 59, 37));
+        assert_eq!(1679086777000, dt.to_millis());
+        assert_eq!(dt, DateTime::from_millis(1679086777511));
+        let dt = DateTime::new(Doy::from_ymd(1969, 12, 31), TimeOfDay::hms(23, 59, 59));
+        assert_eq!(dt, DateTime::from_millis(-1));
+        assert_eq!(-1000, dt.to_millis());
+    }
+
+    #[test]
+    fn convert_to_string() {
+        let dt = DateTime::new(Doy::from_ymd(2023, 3, 17), TimeOfDay::new(14, 30));
+        assert_eq!("20230317T143000", dt.to_string());
+        assert_eq!("2023-03-17T14:30:00", dt.as_iso_date_time());
+    }
+}
@@ -1,18 +1,23 @@
+use crate::date_time::DateTime;
 use crate::day_of_week::DayOfWeek;
-use crate::error::parse_error;
+use crate::error::{error, parse_error, ErrorKind};
+use crate::holiday::HolidayCalendar;
 use crate::month_of_year::Month;
-use crate::DayOfWeek::{Fri, Sun, Thu};
+use crate::period::Period;
+use crate::time_of_day::TimeOfDay;
+use crate::DayOfWeek::{Fri, Sat, Sun, Thu, Wed};
 use crate::TimeWarpError;
-use std::cmp::Ordering;
 use std::convert::TryFrom;
 use std::fmt::{Debug, Display, Formatter};
 use std::num::ParseIntError;
 use std::ops::{Add, Sub};
-use std::time::SystemTime;
+use std::time::{Duration, SystemTime};
 
 /// Day Of Year. Helper-class to easily calculate dates.
+///
+/// All calculations are done on the days since the Unix epoch, so they run in constant time.
 #[must_use]
-#[derive(Eq, PartialEq, Copy, Clone, Debug)]
+#[derive(Eq, PartialEq, Ord, PartialOrd, Hash, Copy, Clone, Debug)]
 pub struct Doy {
     pub year: i32,
     pub doy: i32,
@@ -23,52 +28,91 @@ impl Doy {
     pub const MINUTE: u128 = Self::SECOND * 60;
     pub const HOUR: u128 = Self::MINUTE * 60;
     pub const DAY: u128 = Self::HOUR * 24;
+    #[deprecated(note = "A year is no fixed amount of milliseconds, use `Doy::from_millis`.")]
     pub const YEAR: u128 = Self::DAY * 365 + Self::HOUR * 6;
 
+    /// Days from 0001-01-01 to 1970-01-01.
+    const EPOCH_OFFSET: i64 = 719_162;
+
     /// returns the Doy representing today.
     pub fn today() -> Self {
-        let millis = SystemTime::now()
-            .duration_since(SystemTime::UNIX_EPOCH)
-            .unwrap()
-            .as_millis();
-        Self::from_millis(millis)
+        Self::from(SystemTime::now())
+    }
+
+    /// Days from 1970-01-01 to the 1st, Jan. of `year`.
+    fn days_before_year(year: i64) -> i64 {
+        let y = year - 1;
+        365 * y + y.div_euclid(4) - y.div_euclid(100) + y.div_euclid(400) - Self::EPOCH_OFFSET
+    }
+
+    /// converts the days since 1970-01-01 (Unix epoch) to Doy.
+    pub fn from_unix_days(days: i64) -> Self {
+        // estimate by the mean Gregorian year, off by one year at most.
+        let mut year = 1970 + (days * 400).div_euclid(146_097);
+        if Self::days_before_year(year) > days {
+            year -= 1;
+        } else if Self::days_before_year(year + 1) <= days {
+            year += 1;
+        }
+        Self {
+            year: year as i32,
+            doy: (days - Self::days_before_year(year)) as i32 + 1,
+        }
+    }
+
+    /// returns the days since 1970-01-01 (Unix epoch), negative before.
+    pub fn to_unix_days(self) -> i64 {
+        Self::days_before_year(self.year.into()) + i64::from(self.doy) - 1
     }
 
     /// converts milliseconds from POSIX time or Epoch time to Doy.
-    pub fn from_millis(millis: u128) -> Self {
-        let offset = millis % Self::YEAR;
-        let year = 1970 + ((millis - offset) / Self::YEAR) as i32;
-        let doy_offset = offset % Self::DAY;
-        let doy = 1 + ((offset - doy_offset) / Self::DAY) as i32;
-        Self { year, doy }
+    pub fn from_millis(millis: i64) -> Self {
+        Self::from_unix_days(millis.div_euclid(Self::DAY as i64))
+    }
+
+    /// returns the milliseconds since Epoch at the beginning (00:00 UTC) of this day.
+    pub fn to_millis(self) -> i64 {
+        self.to_unix_days() * Self::DAY as i64
+    }
+
+    /// converts seconds from POSIX time or Epoch time to Doy.
+    pub fn from_secs(secs: i64) -> Self {
+        Self::from_millis(secs * Self::SECOND as i64)
+    }
+
+    /// returns the seconds since Epoch at the beginning (00:00 UTC) of this day.
+    pub fn to_secs(self) -> i64 {
+        self.to_millis() / Self::SECOND as i64
     }
 
     /// Creates a new Doy, by the give `dayOfYear` and the `year`.
     /// 1 = 1. Jan, 32 = 1. Feb, 0 = 31. Dec (year - 1)  
     pub fn new(doy: i32, year: i32) -> Self {
-        if doy < 1 {
-            return Self::new(365 + i32::from(Self::is_leapyear(year - 1)) + doy, year - 1);
-        }
         let max_doy = 365 + i32::from(Self::is_leapyear(year));
-        if doy > max_doy {
-            Self::new(doy - max_doy, year + 1)
-        } else {
+        if (1..=max_doy).contains(&doy) {
             Self { year, doy }
+        } else {
+            Self::from_unix_days(Self::days_before_year(year.into()) + i64::from(doy) - 1)
         }
     }
 
     #[inline]
-    fn day_per_month(year: i32) -> Vec<i32> {
+    fn day_per_month(year: i32) -> [i32; 12] {
         let leap = Self::is_leapyear(year) as i32;
-        vec![31, 28 + leap, 31, 30, 31, 30, 31, 31, 30, 31, 30, 31]
+        [31, 28 + leap, 31, 30, 31, 30, 31, 31, 30, 31, 30, 31]
+    }
+
+    /// The amount of days in `month` (1..12) of `year`.
+    pub(crate) fn days_in_month(year: i32, month: i32) -> i32 {
+        Self::day_per_month(year)[(month - 1) as usize]
     }
 
     /// Creates a Doy from `year`, `month` and `day`.
-    ///
-    /// # Panics
-    /// panics if `month` is not in 1..12
+    /// Values out of range roll over: 2023-02-31 becomes 2023-03-03, month 0 is December of
+    /// the previous year. See `Doy::try_from_ymd` for a strict variant.
     pub fn from_ymd(year: i32, month: i32, day: i32) -> Self {
-        assert!(month > 0 && month < 13, "Month has to be in 1..12");
+        let year = year + (month - 1).div_euclid(12);
+        let month = (month - 1).rem_euclid(12) + 1;
         let day_of_year = Self::day_per_month(year)
             .iter()
             .take(month as usize - 1)
@@ -77,12 +121,55 @@ impl Doy {
         Self::new(day_of_year, year)
     }
 
+    /// Creates a Doy from `year`, `month` and `day`.
+    ///
+    /// # Errors
+    /// if `month` is not in 1..12 or `day` does not exist in that month.
+    pub fn try_from_ymd(year: i32, month: i32, day: i32) -> Result<Self, TimeWarpError> {
+        if !(1..=12).contains(&month) {
+            return error(
+                ErrorKind::InvalidDate,
+                format!("Month out of range 1..12: '{month}'"),
+            );
+        }
+        let days_in_month = Self::days_in_month(year, month);
+        if !(1..=days_in_month).contains(&day) {
+            return error(
+                ErrorKind::InvalidDate,
+                format!("Days exceeded in month {month} '{day}' ({days_in_month})"),
+            );
+        }
+        Ok(Self::from_ymd(year, month, day))
+    }
+
+    /// The amount of iso 8601 weeks in `year`: 52 or 53.
+    pub fn weeks_in_year(year: i32) -> i32 {
+        let jan_1st = Self::new(1, year).day_of_week();
+        if jan_1st == Thu || (jan_1st == Wed && Self::is_leapyear(year)) {
+            53
+        } else {
+            52
+        }
+    }
+
     /// Creates a Doy for the Monday of the given week (iso 8601)
     ///
-    /// # Panics
-    /// panics if `week` is not in 1..53
+    /// # Errors
+    /// if `week` does not exist in `year`.
+    pub fn try_from_week(year: i32, week: i32) -> Result<Self, TimeWarpError> {
+        let weeks = Self::weeks_in_year(year);
+        if !(1..=weeks).contains(&week) {
+            return error(
+                ErrorKind::WeekOutOfRange,
+                format!("Week out of range 1..{weeks}: '{week}'"),
+            );
+        }
+        Ok(Self::from_week(year, week))
+    }
+
+    /// Creates a Doy for the Monday of the given week (iso 8601)
+    /// Weeks out of range roll over into the neighbouring years.
     pub fn from_week(year: i32, week: i32) -> Self {
-        assert!(week > 0 && week < 54, "Week has to be in 1..53");
         // weekday of 4th, Jan.
         let weekday = Self::new(4, year).day_of_week();
         let day_of_year = (week - 1) * 7
@@ -93,10 +180,10 @@ impl Doy {
         Self::new(day_of_year, year)
     }
 
-    /// Is the given `year` a leap-year?
+    /// Is the given `year` a leap-year? (proleptic Gregorian calendar)
     #[inline]
     pub fn is_leapyear(year: i32) -> bool {
-        year % 4 == 0 && year % 100 != 0
+        year % 4 == 0 && (year % 100 != 0 || year % 400 == 0)
     }
 
     /// Is this year a leap-year?
@@ -107,12 +194,10 @@ impl Doy {
     /// converts a *day of year* to `mmdd`.
     fn as_date(self) -> (i32, i32) {
         let mut doy = self.doy;
-        let mut m = 1;
-        for ds in Self::day_per_month(self.year) {
+        for (m, ds) in (1..).zip(Self::day_per_month(self.year)) {
             if doy <= ds {
                 return (m, doy);
             }
-            m += 1;
             doy -= ds;
         }
         (-1, -1)
@@ -126,9 +211,10 @@ impl Doy {
     /// Day of Week
     #[inline]
     pub fn day_of_week(self) -> DayOfWeek {
-        let y = self.year % 100;
-        let y_off = y + (y / 4) + 6 - self.leapyear() as i32;
-        DayOfWeek::from(y_off + self.doy)
+        // Gauss: weekday of 1st, Jan. (0 = Sunday)
+        let y = self.year - 1;
+        let jan_1st = 1 + 5 * y.rem_euclid(4) + 4 * y.rem_euclid(100) + 6 * y.rem_euclid(400);
+        DayOfWeek::from((jan_1st + self.doy - 1).rem_euclid(7))
     }
 
     /// The ISO 8601 Weeks start with Monday and end on Sunday. The first week of the year always
@@ -154,6 +240,191 @@ impl Doy {
     pub fn month(self) -> Month {
         Month::from(self.as_date().0)
     }
+
+    /// The first day of this week, which starts on `first`: Monday in iso 8601.
+    pub fn start_of_week(self, first: DayOfWeek) -> Self {
+        self - (self.day_of_week() as i32 - first as i32).rem_euclid(7)
+    }
+
+    /// The fourth day of this week: Thursday in iso 8601.
+    pub fn middle_of_week(self, first: DayOfWeek) -> Self {
+        self.start_of_week(first) + 3
+    }
+
+    /// The last day of this week.
+    pub fn end_of_week(self, first: DayOfWeek) -> Self {
+        self.start_of_week(first) + 6
+    }
+
+    /// The first day of this month.
+    pub fn start_of_month(self) -> Self {
+        self - (self.day_of_month() - 1)
+    }
+
+    /// The 15th of this month.
+    pub fn middle_of_month(self) -> Self {
+        self.start_of_month() + 14
+    }
+
+    /// The last day of this month.
+    pub fn end_of_month(self) -> Self {
+        let (month, day) = self.as_date();
+        self + (Self::days_in_month(self.year, month) - day)
+    }
+
+    /// Returns the quarter of the year: 1..=4.
+    pub fn quarter(self) -> i32 {
+        (self.as_date().0 - 1) / 3 + 1
+    }
+
+    /// The first day of this quarter.
+    pub fn start_of_quarter(self) -> Self {
+        Self::from_ymd(self.year, (self.quarter() - 1) * 3 + 1, 1)
+    }
+
+    /// The last day of this quarter.
+    pub fn end_of_quarter(self) -> Self {
+        Self::from_ymd(self.year, self.quarter() * 3 + 1, 1) - 1
+    }
+
+    /// The 15th of the second month of this quarter.
+    pub fn middle_of_quarter(self) -> Self {
+        self.start_of_quarter().add_months(1) + 14
+    }
+
+    /// January 1st of this year.
+    pub fn start_of_year(self) -> Self {
+        Self::new(1, self.year)
+    }
+
+    /// July 1st of this year.
+    pub fn middle_of_year(self) -> Self {
+        Self::from_ymd(self.year, 7, 1)
+    }
+
+    /// December 31st of this year.
+    pub fn end_of_year(self) -> Self {
+        Self::from_ymd(self.year, 12, 31)
+    }
+
+    /// Whether this day is a public holiday in `calendar`, weekend substitutes included.
+    pub fn is_holiday(self, calendar: HolidayCalendar) -> bool {
+        calendar.holiday(self).is_some()
+    }
+
+    /// The calendar difference from this day to `end`: "1 year, 2 months, 3 days".
+    pub fn until(self, end: Doy) -> Period {
+        Period::between(self, end)
+    }
+
+    /// The amount of whole weeks from this day to `end`.
+    pub fn weeks_until(self, end: Doy) -> i64 {
+        (end - self) / 7
+    }
+
+    /// Adds (or subtracts) `days`.
+    pub fn add_days(self, days: i32) -> Self {
+        self + days
+    }
+
+    /// Whether this day is neither on a weekend nor a holiday of the optional `holidays`.
+    pub fn is_business_day(self, holidays: Option<HolidayCalendar>) -> bool {
+        !matches!(self.day_of_week(), Sat | Sun) && !holidays.is_some_and(|c| self.is_holiday(c))
+    }
+
+    /// Adds (or subtracts) `days` business days, skipping weekends and the optional `holidays`.
+    /// Friday + 1 business day is Monday, Saturday + 1 business day is Monday as well.
+    pub fn add_business_days(self, days: i32, holidays: Option<HolidayCalendar>) -> Self {
+        let step = i64::from(days.signum());
+        let mut left = i64::from(days).abs();
+        let mut date = self;
+        while left > 0 {
+            if left > 5 {
+                // every 7 days hold 5 weekdays
+                let weeks = (left - 1) / 5;
+                let next = date + weeks * 7 * step;
+                let (start, end) = if step > 0 {
+                    (date + 1, next + 1)
+                } else {
+                    (next, date)
+                };
+                left += holidays.map_or(0, |c| c.weekday_holidays(start, end)) - weeks * 5;
+                date = next;
+            } else {
+                date = date + step;
+                if date.is_business_day(holidays) {
+                    left -= 1;
+                }
+            }
+        }
+        date
+    }
+
+    /// The amount of business days from this day (included) to `end` (excluded), negative if
+    /// `end` is before this day.
+    pub fn business_days_until(self, end: Doy, holidays: Option<HolidayCalendar>) -> i64 {
+        if end < self {
+            return -end.business_days_until(self, holidays);
+        }
+        let weeks = (end - self) / 7;
+        let mut count = weeks * 5;
+        let mut date = self + weeks * 7;
+        while date < end {
+            if !matches!(date.day_of_week(), Sat | Sun) {
+                count += 1;
+            }
+            date = date + 1;
+        }
+        count - holidays.map_or(0, |c| c.weekday_holidays(self, end))
+    }
+
+    /// Adds (or subtracts) `weeks`, the day of week stays the same.
+    pub fn add_weeks(self, weeks: i32) -> Self {
+        self + i64::from(weeks) * 7
+    }
+
+    /// Adds (or subtracts) `months`. The day of month is clamped to the end of the target
+    /// month: Jan 31 + 1 month = Feb 28.
+    pub fn add_months(self, months: i32) -> Self {
+        self.add_months_with(months, EndOfMonth::Clamp)
+    }
+
+    /// Adds (or subtracts) `months`, days beyond the end of the target month are handled
+    /// according to `policy`.
+    pub fn add_months_with(self, months: i32, policy: EndOfMonth) -> Self {
+        self.shift_months(i64::from(months), policy)
+    }
+
+    /// Adds (or subtracts) quarters of three months, see `add_months`.
+    pub fn add_quarters(self, quarters: i32) -> Self {
+        self.shift_months(i64::from(quarters) * 3, EndOfMonth::Clamp)
+    }
+
+    /// Adds (or subtracts) `years`. Feb 29 is clamped to Feb 28 in common years.
+    pub fn add_years(self, years: i32) -> Self {
+        self.add_years_with(years, EndOfMonth::Clamp)
+    }
+
+    /// Adds (or subtracts) `years`, Feb 29 and Feb 28 are handled according to `policy`.
+    pub fn add_years_with(self, years: i32, policy: EndOfMonth) -> Self {
+        self.shift_months(i64::from(years) * 12, policy)
+    }
+
+    fn shift_months(self, months: i64, policy: EndOfMonth) -> Self {
+        let (month, day) = self.as_date();
+        let last_day = day == Self::days_in_month(self.year, month);
+        let months = i64::from(self.year) * 12 + i64::from(month - 1) + months;
+        let year = months.div_euclid(12) as i32;
+        let month = months.rem_euclid(12) as i32 + 1;
+        let days_in_month = Self::days_in_month(year, month);
+        let day = match policy {
+            EndOfMonth::Clamp => day.min(days_in_month),
+            EndOfMonth::Overflow => day,
+            EndOfMonth::Sticky if last_day => days_in_month,
+            EndOfMonth::Sticky => day.min(days_in_month),
+        };
+        Self::from_ymd(year, month, day)
+    }
 }
 
 impl From<Doy> for String {
@@ -164,7 +435,33 @@ impl From<Doy> for String {
 
 impl From<u128> for Doy {
     fn from(value: u128) -> Self {
-        Doy::from_millis(value)
+        Doy::from_millis(i64::try_from(value).unwrap_or(i64::MAX))
+    }
+}
+
+/// The milliseconds from Epoch to `time`, rounded towards the past.
+pub(crate) fn millis_since_epoch(time: SystemTime) -> i64 {
+    match time.duration_since(SystemTime::UNIX_EPOCH) {
+        Ok(after) => after.as_millis() as i64,
+        Err(before) => -(before.duration().as_nanos().div_ceil(1_000_000) as i64),
+    }
+}
+
+impl From<SystemTime> for Doy {
+    fn from(time: SystemTime) -> Self {
+        Doy::from_millis(millis_since_epoch(time))
+    }
+}
+
+impl From<Doy> for SystemTime {
+    fn from(doy: Doy) -> Self {
+        let millis = doy.to_millis();
+        let duration = Duration::from_millis(millis.unsigned_abs());
+        if millis < 0 {
+            SystemTime::UNIX_EPOCH - duration
+        } else {
+            SystemTime::UNIX_EPOCH + duration
+        }
     }
 }
 
@@ -187,7 +484,7 @@ macro_rules! gen_calcs {
             type Output = Doy;
 
             fn add(self, rhs: $key) -> Self::Output {
-                Doy::new(self.doy + rhs as i32, self.year)
+                Doy::from_unix_days(self.to_unix_days() + rhs as i64)
             }
         }
 
@@ -195,7 +492,7 @@ macro_rules! gen_calcs {
             type Output = Doy;
 
             fn sub(self, rhs: $key) -> Self::Output {
-                Doy::new(self.doy - rhs as i32, self.year)
+                Doy::from_unix_days(self.to_unix_days() - rhs as i64)
             }
         }
     )+
@@ -204,32 +501,12 @@ macro_rules! gen_calcs {
 
 gen_calcs!(i8, i16, i32, i64, u8, u16, u32, u64, DayOfWeek);
 
-impl PartialOrd for Doy {
-    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
-        let p = if self.lt(other) {
-            Ordering::Less
-        } else if self.gt(other) {
-            Ordering::Greater
-        } else {
-            Ordering::Equal
-        };
-        Some(p)
-    }
-
-    fn lt(&self, other: &Self) -> bool {
-        self.year < other.year || (self.year == other.year && self.doy < other.doy)
-    }
-
-    fn le(&self, other: &Self) -> bool {
-        self.year < other.year || (self.year == other.year && self.doy <= other.doy)
-    }
-
-    fn gt(&self, other: &Self) -> bool {
-        self.year > other.year || (self.year == other.year && self.doy > other.doy)
-    }
+/// The amount of days between two dates.
+impl Sub<Doy> for Doy {
+    type Output = i64;
 
-    fn ge(&self, other: &Self) -> bool {
-        self.year > other.year || (self.year == other.year && self.doy >= other.doy)
+    fn sub(self, rhs: Doy) -> Self::Output {
+        self.to_unix_days() - rhs.to_unix_days()
     }
 }
 
@@ -255,25 +532,29 @@ impl TryFrom<&str> for Doy {
         } else {
             return parse_error(format!("Wrong date-format: '{value}'"));
         };
-        if m < 1 || m > 12 {
-            return parse_error(format!("Month out of range 0..12: '{m}'"));
-        }
-        let days_in_month = Self::day_per_month(y).as_slice()[(m - 1) as usize];
-        if d < 1 || d > days_in_month {
-            return parse_error(format!(
-                "Days exceeded in month {m} '{d}' ({days_in_month})"
-            ));
-        }
-        Ok(Self::from_ymd(y, m, d))
+        Self::try_from_ymd(y, m, d)
     }
 }
 
-/// A timespan in whole days.
+/// How month and year arithmetic handles days beyond the end of the target month.
+#[derive(Debug, Default, Copy, Clone, Eq, PartialEq)]
+pub enum EndOfMonth {
+    /// Clamps to the last day of the month: Jan 31 + 1 month = Feb 28.
+    #[default]
+    Clamp,
+    /// Rolls over into the next month: Jan 31 + 1 month = Mar 3.
+    Overflow,
+    /// Keeps the last day of a month: Feb 28 + 1 month = Mar 31, otherwise clamps.
+    Sticky,
+}
+
+/// A timespan in whole days, or a single point in time.
 ///
-#[derive(Debug, Eq, PartialEq)]
+#[derive(Debug, Eq, PartialEq, Copy, Clone)]
 pub enum Tempus {
     Moment(Doy),
     Interval(Doy, Doy),
+    Instant(DateTime),
 }
 
 impl Tempus {
@@ -281,6 +562,7 @@ impl Tempus {
     pub fn start(&self) -> Doy {
         match *self {
             Tempus::Moment(d) | Tempus::Interval(d, _) => d,
+            Tempus::Instant(dt) => dt.date,
         }
     }
 
@@ -289,6 +571,15 @@ impl Tempus {
         match *self {
             Tempus::Moment(d) => d + 1,
             Tempus::Interval(_, e) => e,
+            Tempus::Instant(dt) => dt.date + 1,
+        }
+    }
+
+    /// The time of day, if this `Tempus` is an `Instant`.
+    pub fn time(&self) -> Option<TimeOfDay> {
+        match *self {
+            Tempus::Instant(dt) => Some(dt.time),
+            _ => None,
         }
     }
 }
@@ -296,7 +587,9 @@ impl Tempus {
 #[cfg(test)]
 mod should {
     use crate::day_of_week::DayOfWeek::*;
-    use crate::doy::Doy;
+    use crate::doy::{Doy, EndOfMonth};
+    use crate::error::ErrorKind;
+    use crate::holiday::HolidayCalendar;
     use crate::month_of_year::Month;
     use std::convert::TryFrom;
 
@@ -312,6 +605,44 @@ mod should {
         assert!(Doy::try_from("2018/04/15").is_err());
     }
 
+    #[test]
+    fn try_from_ymd() {
+        assert_eq!(
+            Doy::from_ymd(2024, 2, 29),
+            Doy::try_from_ymd(2024, 2, 29).unwrap()
+        );
+        assert!(Doy::try_from_ymd(2023, 2, 29).is_err());
+        assert!(Doy::try_from_ymd(2023, 2, 31).is_err());
+        assert!(Doy::try_from_ymd(2023, 0, 1).is_err());
+        assert!(Doy::try_from_ymd(2023, 13, 1).is_err());
+        assert!(Doy::try_from_ymd(2023, 4, 0).is_err());
+    }
+
+    #[test]
+    fn roll_over_from_ymd() {
+        assert_eq!("2023-03-03", Doy::from_ymd(2023, 2, 31).as_iso_date());
+        assert_eq!("2022-12-05", Doy::from_ymd(2023, 0, 5).as_iso_date());
+        assert_eq!("2024-01-05", Doy::from_ymd(2023, 13, 5).as_iso_date());
+        assert_eq!("2022-12-31", Doy::from_ymd(2023, 1, 0).as_iso_date());
+    }
+
+    #[test]
+    fn try_from_week() {
+        assert_eq!(53, Doy::weeks_in_year(2020));
+        assert_eq!(52, Doy::weeks_in_year(2023));
+        assert_eq!(53, Doy::weeks_in_year(2026));
+        assert_eq!(
+            "2020-12-28",
+            Doy::try_from_week(2020, 53).unwrap().as_iso_date()
+        );
+        assert_eq!(
+            ErrorKind::WeekOutOfRange,
+            Doy::try_from_week(2023, 53).unwrap_err().kind()
+        );
+        assert!(Doy::try_from_week(2023, 0).is_err());
+        assert_eq!("2024-01-01", Doy::from_week(2023, 53).as_iso_date());
+    }
+
     #[test]
     fn from_week_of_year() {
         assert_eq!("2018-01-01", Doy::from_week(2018, 1).as_iso_date());
@@ -369,7 +700,10 @@ mod should {
     fn return_leapyear() {
         assert!(Doy::new(1, 2020).leapyear());
         assert!(!Doy::new(1, 2018).leapyear());
-        assert!(!Doy::new(1, 2000).leapyear());
+        assert!(Doy::new(1, 2000).leapyear());
+        assert!(!Doy::new(1, 1900).leapyear());
+        assert!(!Doy::new(1, 2100).leapyear());
+        assert!(Doy::new(1, 1600).leapyear());
     }
 
     #[test]
@@ -387,6 +721,50 @@ mod should {
         assert_eq!(Tue, Doy::new(359, 2018).day_of_week());
         assert_eq!(Fri, Doy::new(360, 2020).day_of_week());
         assert_eq!(Sat, Doy::new(359, 2021).day_of_week());
+        // outside of 2000..2099
+        assert_eq!(Sat, Doy::from_ymd(2000, 1, 1).day_of_week());
+        assert_eq!(Tue, Doy::from_ymd(2000, 2, 29).day_of_week());
+        assert_eq!(Thu, Doy::from_ymd(1970, 1, 1).day_of_week());
+        assert_eq!(Fri, Doy::from_ymd(1582, 10, 15).day_of_week());
+        assert_eq!(Fri, Doy::from_ymd(2100, 1, 1).day_of_week());
+    }
+
+    /// Days since 1970-01-01 (H. Hinnant, `days_from_civil`) - independent of `Doy`.
+    fn epoch_day(y: i64, m: i64, d: i64) -> i64 {
+        let y = if m <= 2 { y - 1 } else { y };
+        let era = y.div_euclid(400);
+        let yoe = y - era * 400;
+        let mp = (m + 9) % 12;
+        let doy = (153 * mp + 2) / 5 + d - 1;
+        let doe = yoe * 365 + yoe / 4 - yoe / 100 + doy;
+        era * 146_097 + doe - 719_468
+    }
+
+    #[test]
+    fn match_proleptic_gregorian_calendar() {
+        for year in -1200..=3200 {
+            let mut doy = 0;
+            for month in 1..=12 {
+                let first = epoch_day(year.into(), month, 1);
+                let days_in_month = if month == 12 {
+                    epoch_day(i64::from(year) + 1, 1, 1)
+                } else {
+                    epoch_day(year.into(), month + 1, 1)
+                } - first;
+                for day in 1..=days_in_month {
+                    doy += 1;
+                    let date = Doy::from_ymd(year, month as i32, day as i32);
+                    assert_eq!(Doy::new(doy, year), date, "{year}-{month}-{day}");
+                    assert_eq!((month as i32, day as i32), date.as_date(), "{date}");
+                    assert_eq!(
+                        (first + day + 3).rem_euclid(7) as i32,
+                        date.day_of_week() as i32,
+                        "{date}"
+                    );
+                }
+            }
+            assert_eq!(365 + i32::from(Doy::is_leapyear(year)), doy, "{year}");
+        }
     }
 
     #[test]
@@ -416,10 +794,258 @@ mod should {
         assert_eq!(Doy::new(17, 2020), d);
     }
 
+    #[test]
+    fn sub_doy() {
+        let a = Doy::from_ymd(2023, 3, 17);
+        assert_eq!(0, a - a);
+        assert_eq!(366, Doy::from_ymd(2024, 3, 17) - a);
+        assert_eq!(-365, Doy::from_ymd(2022, 3, 17) - a);
+        assert_eq!(2, a.weeks_until(a + 20));
+        assert_eq!(-2, a.weeks_until(a - 20));
+    }
+
+    #[test]
+    fn find_quarters() {
+        assert_eq!(1, Doy::from_ymd(2023, 1, 1).quarter());
+        assert_eq!(1, Doy::from_ymd(2023, 3, 31).quarter());
+        assert_eq!(2, Doy::from_ymd(2023, 4, 1).quarter());
+        assert_eq!(4, Doy::from_ymd(2023, 12, 31).quarter());
+        let today = Doy::from_ymd(2023, 8, 17);
+        assert_eq!(Doy::from_ymd(2023, 7, 1), today.start_of_quarter());
+        assert_eq!(Doy::from_ymd(2023, 9, 30), today.end_of_quarter());
+        let new_years_eve = Doy::from_ymd(2024, 12, 31);
+        assert_eq!(Doy::from_ymd(2024, 10, 1), new_years_eve.start_of_quarter());
+        assert_eq!(new_years_eve, new_years_eve.end_of_quarter());
+    }
+
+    #[test]
+    fn find_boundaries() {
+        // Fri 2023-03-17
+        let today = Doy::from_ymd(2023, 3, 17);
+        assert_eq!(Doy::from_ymd(2023, 3, 13), today.start_of_week(Mon));
+        assert_eq!(Doy::from_ymd(2023, 3, 16), today.middle_of_week(Mon));
+        assert_eq!(Doy::from_ymd(2023, 3, 19), today.end_of_week(Mon));
+        assert_eq!(Doy::from_ymd(2023, 3, 12), today.start_of_week(Sun));
+        assert_eq!(Doy::from_ymd(2023, 3, 18), today.end_of_week(Sun));
+        let sunday = Doy::from_ymd(2023, 3, 19);
+        assert_eq!(Doy::from_ymd(2023, 3, 13), sunday.start_of_week(Mon));
+        assert_eq!(sunday, sunday.start_of_week(Sun));
+        assert_eq!(Doy::from_ymd(2023, 3, 1), today.start_of_month());
+        assert_eq!(Doy::from_ymd(2023, 3, 15), today.middle_of_month());
+        assert_eq!(Doy::from_ymd(2023, 3, 31), today.end_of_month());
+        assert_eq!(
+            Doy::from_ymd(2024, 2, 29),
+            Doy::from_ymd(2024, 2, 1).end_of_month()
+        );
+        assert_eq!(Doy::from_ymd(2023, 2, 15), today.middle_of_quarter());
+        assert_eq!(Doy::from_ymd(2023, 1, 1), today.start_of_year());
+        assert_eq!(Doy::from_ymd(2023, 7, 1), today.middle_of_year());
+        assert_eq!(Doy::from_ymd(2023, 12, 31), today.end_of_year());
+    }
+
+    #[test]
+    fn add_units() {
+        let today = Doy::from_ymd(2023, 3, 17);
+        assert_eq!(Doy::from_ymd(2023, 3, 22), today.add_days(5));
+        assert_eq!(Doy::from_ymd(2023, 3, 12), today.add_days(-5));
+        assert_eq!(Doy::from_ymd(2023, 3, 31), today.add_weeks(2));
+        assert_eq!(Doy::from_ymd(2023, 2, 24), today.add_weeks(-3));
+        assert_eq!(today.day_of_week(), today.add_weeks(-3).day_of_week());
+        assert_eq!(Doy::from_ymd(2023, 5, 17), today.add_months(2));
+        assert_eq!(Doy::from_ymd(2022, 2, 17), today.add_months(-13));
+        assert_eq!(Doy::from_ymd(2023, 9, 17), today.add_quarters(2));
+        assert_eq!(Doy::from_ymd(2022, 12, 17), today.add_quarters(-1));
+        assert_eq!(Doy::from_ymd(2025, 3, 17), today.add_years(2));
+        assert_eq!(Doy::from_ymd(2022, 3, 17), today.add_years(-1));
+    }
+
+    #[test]
+    fn add_business_days() {
+        // Fri 2023-03-17
+        let today = Doy::from_ymd(2023, 3, 17);
+        assert_eq!(Doy::from_ymd(2023, 3, 20), today.add_business_days(1, None));
+        assert_eq!(Doy::from_ymd(2023, 3, 22), today.add_business_days(3, None));
+        assert_eq!(
+            Doy::from_ymd(2023, 3, 10),
+            today.add_business_days(-5, None)
+        );
+        assert_eq!(
+            Doy::from_ymd(2023, 4, 28),
+            today.add_business_days(30, None)
+        );
+        assert_eq!(today, today.add_business_days(0, None));
+        let saturday = Doy::from_ymd(2023, 3, 18);
+        assert_eq!(
+            Doy::from_ymd(2023, 3, 20),
+            saturday.add_business_days(1, None)
+        );
+        assert_eq!(
+            Doy::from_ymd(2023, 3, 17),
+            saturday.add_business_days(-1, None)
+        );
+        // Good Friday and Easter Monday 2024
+        let de = Some(HolidayCalendar::De(None));
+        let thursday = Doy::from_ymd(2024, 3, 28);
+        assert_eq!(Doy::from_ymd(2024, 4, 2), thursday.add_business_days(1, de));
+        assert_eq!(
+            thursday,
+            Doy::from_ymd(2024, 4, 2).add_business_days(-1, de)
+        );
+        assert_eq!(
+            Doy::from_ymd(2024, 5, 15),
+            thursday.add_business_days(30, de)
+        );
+        assert!(!thursday
+            .add_business_days(1, de)
+            .add_days(-4)
+            .is_business_day(de));
+    }
+
+    #[test]
+    fn count_business_days() {
+        let today = Doy::from_ymd(2023, 3, 17);
+        assert_eq!(
+            5,
+            today.business_days_until(Doy::from_ymd(2023, 3, 24), None)
+        );
+        assert_eq!(
+            1,
+            today.business_days_until(Doy::from_ymd(2023, 3, 20), None)
+        );
+        assert_eq!(
+            -5,
+            Doy::from_ymd(2023, 3, 24).business_days_until(today, None)
+        );
+        assert_eq!(0, today.business_days_until(today, None));
+        let de = Some(HolidayCalendar::De(None));
+        let new_year = Doy::from_ymd(2024, 1, 1);
+        assert_eq!(
+            253,
+            new_year.business_days_until(Doy::from_ymd(2025, 1, 1), de)
+        );
+        for start in [
+            today,
+            Doy::from_ymd(2023, 12, 20),
+            Doy::from_ymd(2024, 3, 27),
+        ] {
+            for days in -60..60 {
+                let end = start.add_business_days(days, de);
+                assert_eq!(i64::from(days), start.business_days_until(end, de));
+            }
+        }
+    }
+
+    #[test]
+    fn clamp_to_end_of_month() {
+        let jan_31 = Doy::from_ymd(2023, 1, 31);
+        assert_eq!(Doy::from_ymd(2023, 2, 28), jan_31.add_months(1));
+        assert_eq!(Doy::from_ymd(2024, 2, 29), jan_31.add_months(13));
+        assert_eq!(Doy::from_ymd(2022, 11, 30), jan_31.add_months(-2));
+        assert_eq!(Doy::from_ymd(2023, 4, 30), jan_31.add_quarters(1));
+        let leap_day = Doy::from_ymd(2024, 2, 29);
+        assert_eq!(Doy::from_ymd(2025, 2, 28), leap_day.add_years(1));
+        assert_eq!(Doy::from_ymd(2028, 2, 29), leap_day.add_years(4));
+        assert_eq!(Doy::from_ymd(2023, 2, 28), leap_day.add_years(-1));
+        let dec_31 = Doy::from_ymd(2024, 12, 31);
+        assert_eq!(Doy::from_ymd(2025, 12, 31), dec_31.add_years(1));
+    }
+
+    #[test]
+    fn apply_end_of_month_policies() {
+        let jan_31 = Doy::from_ymd(2023, 1, 31);
+        assert_eq!(
+            Doy::from_ymd(2023, 3, 3),
+            jan_31.add_months_with(1, EndOfMonth::Overflow)
+        );
+        assert_eq!(
+            Doy::from_ymd(2023, 2, 28),
+            jan_31.add_months_with(1, EndOfMonth::Sticky)
+        );
+        let feb_28 = Doy::from_ymd(2023, 2, 28);
+        assert_eq!(
+            Doy::from_ymd(2023, 3, 28),
+            feb_28.add_months_with(1, EndOfMonth::Clamp)
+        );
+        assert_eq!(
+            Doy::from_ymd(2023, 3, 31),
+            feb_28.add_months_with(1, EndOfMonth::Sticky)
+        );
+        assert_eq!(
+            Doy::from_ymd(2022, 11, 30),
+            feb_28.add_months_with(-3, EndOfMonth::Sticky)
+        );
+        assert_eq!(
+            Doy::from_ymd(2024, 2, 29),
+            feb_28.add_years_with(1, EndOfMonth::Sticky)
+        );
+        let leap_day = Doy::from_ymd(2024, 2, 29);
+        assert_eq!(
+            Doy::from_ymd(2025, 3, 1),
+            leap_day.add_years_with(1, EndOfMonth::Overflow)
+        );
+        assert_eq!(
+            Doy::from_ymd(2025, 2, 28),
+            leap_day.add_years_with(1, EndOfMonth::Sticky)
+        );
+        let mar_17 = Doy::from_ymd(2024, 3, 17);
+        for policy in [EndOfMonth::Clamp, EndOfMonth::Overflow, EndOfMonth::Sticky] {
+            assert_eq!(Doy::from_ymd(2025, 3, 17), mar_17.add_years_with(1, policy));
+        }
+    }
+
+    #[test]
+    fn add_large_offsets() {
+        let today = Doy::from_ymd(2023, 3, 17);
+        assert_eq!(Doy::from_ymd(4761, 2, 11), today + 1_000_000);
+        assert_eq!(today, today + 1_000_000 - 1_000_000u32);
+        assert_eq!(Doy::from_ymd(-715_309, 7, 28), today - 262_000_000i64);
+        assert_eq!(Doy::from_ymd(2030, 6, 30), Doy::new(2738, 2023));
+        assert_eq!(Doy::from_ymd(2015, 10, 16), Doy::new(-2633, 2023));
+    }
+
     #[test]
     fn from_millis() {
         assert_eq!("20230317", Doy::from_millis(1679086777511).to_string());
         assert_eq!("20230101", Doy::from_millis(1672570315000).to_string());
         assert_eq!("20181231", Doy::from_millis(1546253515000).to_string());
+        // year boundaries
+        assert_eq!("20231231", Doy::from_millis(1704067199999).to_string());
+        assert_eq!("20240101", Doy::from_millis(1704067200000).to_string());
+        assert_eq!("20001231", Doy::from_millis(978263999000).to_string());
+        // before 1970
+        assert_eq!("19691231", Doy::from_millis(-1).to_string());
+        assert_eq!("19700101", Doy::from_millis(0).to_string());
+        assert_eq!("19000101", Doy::from_secs(-2208988800).to_string());
+    }
+
+    #[test]
+    fn to_millis() {
+        assert_eq!(1704067200000, Doy::from_ymd(2024, 1, 1).to_millis());
+        assert_eq!(-86400, Doy::from_ymd(1969, 12, 31).to_secs());
+        assert_eq!(0, Doy::from_ymd(1970, 1, 1).to_unix_days());
+        assert_eq!(11016, Doy::from_ymd(2000, 2, 29).to_unix_days());
+    }
+
+    #[test]
+    fn round_trip_unix_days() {
+        for days in (-800_000..800_000).step_by(7) {
+            assert_eq!(days, Doy::from_unix_days(days).to_unix_days());
+        }
+        let mut expected = Doy::from_ymd(1900, 1, 1);
+        for days in -25567..30000 {
+            assert_eq!(expected, Doy::from_unix_days(days));
+            expected = expected + 1;
+        }
+    }
+
+    #[test]
+    fn round_trip_system_time() {
+        use std::time::{Duration, SystemTime};
+        let doy = Doy::from_ymd(1955, 11, 5);
+        assert_eq!(doy, Doy::from(SystemTime::from(doy)));
+        let doy = Doy::from_ymd(2015, 10, 21);
+        assert_eq!(doy, Doy::from(SystemTime::from(doy)));
+        let before_epoch = SystemTime::UNIX_EPOCH - Duration::from_millis(1);
+        assert_eq!(Doy::from_ymd(1969, 12, 31), Doy::from(before_epoch));
     }
 }
@@ -1,22 +1,121 @@
-use self::TimeWarpError::ParseError;
 use crate::date_matcher::Rule;
+use pest::error::{ErrorVariant, InputLocation};
 use std::fmt::{Debug, Display, Formatter};
-use std::num::ParseIntError;
+use std::num::{IntErrorKind, ParseIntError};
+use std::ops::Range;
 
-#[derive(Debug)]
-pub enum TimeWarpError {
-    ParseError(String),
+/// The kind of a `TimeWarpError`.
+#[derive(Debug, Copy, Clone, Eq, PartialEq)]
+pub enum ErrorKind {
+    /// The input, or a part of it, is not understood.
+    Unrecognized,
+    /// The date does not exist in the calendar, like "31.2.23".
+    InvalidDate,
+    /// The week does not exist in the year, like "KW 23/53".
+    WeekOutOfRange,
+    /// The input can be read in more than one way.
+    Ambiguous,
+    /// A number or a date exceeds the supported range.
+    Overflow,
 }
 
-impl From<pest::error::Error<Rule>> for TimeWarpError {
-    fn from(value: pest::error::Error<Rule>) -> Self {
-        ParseError(value.to_string())
+/// Error with its `ErrorKind`, the byte span of the offending part of the input and the
+/// alternatives that were expected there.
+#[derive(Debug, Clone, Eq, PartialEq)]
+pub struct TimeWarpError {
+    kind: ErrorKind,
+    message: String,
+    span: Option<Range<usize>>,
+    expected: Vec<String>,
+}
+
+impl TimeWarpError {
+    pub fn new(kind: ErrorKind, message: impl Into<String>) -> Self {
+        Self {
+            kind,
+            message: message.into(),
+            span: None,
+            expected: vec![],
+        }
+    }
+
+    /// Attaches the `span` of the offending input, unless a narrower one is known already.
+    #[must_use]
+    pub fn at(mut self, span: Range<usize>) -> Self {
+        self.span.get_or_insert(span);
+        self
+    }
+
+    pub fn kind(&self) -> ErrorKind {
+        self.kind
+    }
+
+    pub fn message(&self) -> &str {
+        &self.message
+    }
+
+    /// The byte span of the offending part of the input, if known.
+    pub fn span(&self) -> Option<Range<usize>> {
+        self.span.clone()
+    }
+
+    /// The alternatives that would have been accepted at the start of `span`.
+    pub fn expected(&self) -> &[String] {
+        &self.expected
+    }
+
+    /// Converts a pest-error on `input`. The span covers the unrecognized word.
+    pub(crate) fn from_pest(value: pest::error::Error<Rule>, input: &str) -> Self {
+        let (start, end) = match value.location {
+            InputLocation::Pos(pos) => {
+                let end = input[pos..]
+                    .find(char::is_whitespace)
+                    .map_or(input.len(), |len| pos + len);
+                (pos, end)
+            }
+            InputLocation::Span(span) => span,
+        };
+        let expected: Vec<String> = match value.variant {
+            ErrorVariant::ParsingError { positives, .. } => {
+                let mut expected: Vec<String> = positives.into_iter().map(describe).collect();
+                expected.sort();
+                expected.dedup();
+                expected
+            }
+            ErrorVariant::CustomError { .. } => vec![],
+        };
+        let found = &input[start..end];
+        let message = if found.is_empty() {
+            format!("Unexpected end of input, expected {}", expected.join(", "))
+        } else {
+            format!("Unrecognized '{found}', expected {}", expected.join(", "))
+        };
+        Self {
+            kind: ErrorKind::Unrecognized,
+            message,
+            span: Some(start..end),
+            expected,
+        }
+    }
+}
+
+/// A readable name of the grammar-`rule`.
+fn describe(rule: Rule) -> String {
+    match rule {
+        Rule::date_matcher | Rule::range | Rule::point | Rule::date => "date".into(),
+        Rule::time | Rule::clock | Rule::hour_only => "time".into(),
+        Rule::EOI => "end of input".into(),
+        _ => format!("{rule:?}").replace('_', " "),
     }
 }
 
 impl From<ParseIntError> for TimeWarpError {
     fn from(value: ParseIntError) -> Self {
-        ParseError(value.to_string())
+        let kind = match value.kind() {
+            IntErrorKind::PosOverflow | IntErrorKind::NegOverflow => ErrorKind::Overflow,
+            _ => ErrorKind::Unrecognized,
+        };
+        Self::new(kind, value.to_string())
     }
 }
 
@@ -33,13 +132,19 @@ where
 
 impl Display for TimeWarpError {
     fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
-        match &self {
-            ParseError(str) => write!(f, "{str}"),
+        match &self.span {
+            Some(span) => write!(f, "{} (at {}..{})", self.message, span.start, span.end),
+            None => write!(f, "{}", self.message),
         }
     }
 }
 
+#[inline]
+pub fn error<T>(kind: ErrorKind, str: impl Into<String>) -> Result<T, TimeWarpError> {
+    Err::<T, TimeWarpError>(TimeWarpError::new(kind, str))
+}
+
 #[inline]
 pub fn parse_error<T>(str: impl Into<String>) -> Result<T, TimeWarpError> {
-    Err::<T, TimeWarpError>(ParseError(str.into()))
+    error(ErrorKind::Unrecognized, str)
 }
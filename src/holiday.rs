@@ -0,0 +1,543 @@
+use self::GermanState::*;
+use self::Holiday::*;
+use self::HolidayCalendar::{At, Ch, De, Uk, Us};
+use crate::date_matcher::Rule;
+use crate::day_of_week::DayOfWeek;
+use crate::day_of_week::DayOfWeek::{Mon, Sat, Sun, Thu, Wed};
+use crate::doy::Doy;
+
+/// Public holidays, fixed ones and those moving with Easter or a weekday.
+#[derive(Debug, Copy, Clone, Eq, PartialEq, Hash)]
+pub enum Holiday {
+    /// 1st of January
+    NewYear,
+    /// 6th of January, "Heilige Drei Könige"
+    Epiphany,
+    /// 3rd Monday of January
+    MartinLutherKingDay,
+    /// 3rd Monday of February
+    PresidentsDay,
+    /// 8th of March, "Frauentag"
+    WomensDay,
+    /// "Karfreitag"
+    GoodFriday,
+    /// "Ostersonntag"
+    EasterSunday,
+    /// "Ostermontag"
+    EasterMonday,
+    /// 1st of May, "Tag der Arbeit"
+    WorkersDay,
+    /// 1st Monday of May
+    EarlyMayBankHoliday,
+    /// 39 days after Easter, "Christi Himmelfahrt"
+    Ascension,
+    /// 49 days after Easter, "Pfingstsonntag"
+    WhitSunday,
+    /// 50 days after Easter, "Pfingstmontag"
+    WhitMonday,
+    /// 60 days after Easter, "Fronleichnam"
+    CorpusChristi,
+    /// last Monday of May
+    SpringBankHoliday,
+    /// last Monday of May
+    MemorialDay,
+    /// 19th of June
+    Juneteenth,
+    /// 4th of July
+    IndependenceDay,
+    /// 1st of August, "Bundesfeier"
+    SwissNationalDay,
+    /// 15th of August, "Mariä Himmelfahrt"
+    Assumption,
+    /// last Monday of August
+    SummerBankHoliday,
+    /// 1st Monday of September
+    LaborDay,
+    /// 20th of September, "Weltkindertag"
+    WorldChildrensDay,
+    /// 3rd of October, "Tag der Deutschen Einheit"
+    GermanUnityDay,
+    /// 2nd Monday of October
+    ColumbusDay,
+    /// 26th of October, "Nationalfeiertag"
+    AustrianNationalDay,
+    /// 31st of October
+    ReformationDay,
+    /// 1st of November, "Allerheiligen"
+    AllSaints,
+    /// 11th of November
+    VeteransDay,
+    /// Wednesday before the 23rd of November, "Buß- und Bettag"
+    RepentanceDay,
+    /// 4th Thursday of November
+    Thanksgiving,
+    /// 8th of December, "Mariä Empfängnis"
+    ImmaculateConception,
+    /// 25th of December
+    ChristmasDay,
+    /// 26th of December, "Stefanitag" or Boxing Day
+    StStephensDay,
+    /// 24th of December, "Heiligabend", a named day but no public holiday
+    ChristmasEve,
+    /// 31st of December, "Silvester", a named day but no public holiday
+    NewYearsEve,
+}
+
+impl Holiday {
+    pub const ALL: [Holiday; 36] = [
+        NewYear,
+        Epiphany,
+        MartinLutherKingDay,
+        PresidentsDay,
+        WomensDay,
+        GoodFriday,
+        EasterSunday,
+        EasterMonday,
+        WorkersDay,
+        EarlyMayBankHoliday,
+        Ascension,
+        WhitSunday,
+        WhitMonday,
+        CorpusChristi,
+        SpringBankHoliday,
+        MemorialDay,
+        Juneteenth,
+        IndependenceDay,
+        SwissNationalDay,
+        Assumption,
+        SummerBankHoliday,
+        LaborDay,
+        WorldChildrensDay,
+        GermanUnityDay,
+        ColumbusDay,
+        AustrianNationalDay,
+        ReformationDay,
+        AllSaints,
+        VeteransDay,
+        RepentanceDay,
+        Thanksgiving,
+        ImmaculateConception,
+        ChristmasDay,
+        StStephensDay,
+        ChristmasEve,
+        NewYearsEve,
+    ];
+
+    pub(crate) fn from_holiday(rule: Rule) -> Option<Self> {
+        let holiday = match rule {
+            Rule::new_year => NewYear,
+            Rule::epiphany => Epiphany,
+            Rule::good_friday => GoodFriday,
+            Rule::easter_sunday => EasterSunday,
+            Rule::easter_monday => EasterMonday,
+            Rule::workers_day => WorkersDay,
+            Rule::ascension => Ascension,
+            Rule::whit_sunday => WhitSunday,
+            Rule::whit_monday => WhitMonday,
+            Rule::corpus_christi => CorpusChristi,
+            Rule::memorial_day => MemorialDay,
+            Rule::independence_day => IndependenceDay,
+            Rule::labor_day => LaborDay,
+            Rule::german_unity_day => GermanUnityDay,
+            Rule::reformation_day => ReformationDay,
+            Rule::all_saints => AllSaints,
+            Rule::thanksgiving => Thanksgiving,
+            Rule::christmas_eve => ChristmasEve,
+            Rule::christmas_day => ChristmasDay,
+            Rule::st_stephens_day => StStephensDay,
+            Rule::new_years_eve => NewYearsEve,
+            _ => return None,
+        };
+        Some(holiday)
+    }
+
+    /// The date of this holiday in `year`, without moving it off a weekend.
+    pub fn date(self, year: i32) -> Doy {
+        let easter = easter_sunday(year);
+        match self {
+            NewYear => Doy::from_ymd(year, 1, 1),
+            Epiphany => Doy::from_ymd(year, 1, 6),
+            MartinLutherKingDay => nth_weekday(year, 1, 3, Mon),
+            PresidentsDay => nth_weekday(year, 2, 3, Mon),
+            WomensDay => Doy::from_ymd(year, 3, 8),
+            GoodFriday => easter - 2,
+            EasterSunday => easter,
+            EasterMonday => easter + 1,
+            WorkersDay => Doy::from_ymd(year, 5, 1),
+            EarlyMayBankHoliday => nth_weekday(year, 5, 1, Mon),
+            Ascension => easter + 39,
+            WhitSunday => easter + 49,
+            WhitMonday => easter + 50,
+            CorpusChristi => easter + 60,
+            SpringBankHoliday | MemorialDay => last_weekday(year, 5, Mon),
+            Juneteenth => Doy::from_ymd(year, 6, 19),
+            IndependenceDay => Doy::from_ymd(year, 7, 4),
+            SwissNationalDay => Doy::from_ymd(year, 8, 1),
+            Assumption => Doy::from_ymd(year, 8, 15),
+            SummerBankHoliday => last_weekday(year, 8, Mon),
+            LaborDay => nth_weekday(year, 9, 1, Mon),
+            WorldChildrensDay => Doy::from_ymd(year, 9, 20),
+            GermanUnityDay => Doy::from_ymd(year, 10, 3),
+            ColumbusDay => nth_weekday(year, 10, 2, Mon),
+            AustrianNationalDay => Doy::from_ymd(year, 10, 26),
+            ReformationDay => Doy::from_ymd(year, 10, 31),
+            AllSaints => Doy::from_ymd(year, 11, 1),
+            VeteransDay => Doy::from_ymd(year, 11, 11),
+            RepentanceDay => {
+                let nov_22 = Doy::from_ymd(year, 11, 22);
+                nov_22 - nov_22.day_of_week().days_before(Wed) % 7
+            }
+            Thanksgiving => nth_weekday(year, 11, 4, Thu),
+            ImmaculateConception => Doy::from_ymd(year, 12, 8),
+            ChristmasDay => Doy::from_ymd(year, 12, 25),
+            StStephensDay => Doy::from_ymd(year, 12, 26),
+            ChristmasEve => Doy::from_ymd(year, 12, 24),
+            NewYearsEve => Doy::from_ymd(year, 12, 31),
+        }
+    }
+}
+
+/// The federal states of Germany.
+#[derive(Debug, Copy, Clone, Eq, PartialEq, Hash)]
+pub enum GermanState {
+    BadenWuerttemberg,
+    Bavaria,
+    Berlin,
+    Brandenburg,
+    Bremen,
+    Hamburg,
+    Hesse,
+    LowerSaxony,
+    MecklenburgVorpommern,
+    NorthRhineWestphalia,
+    RhinelandPalatinate,
+    Saarland,
+    Saxony,
+    SaxonyAnhalt,
+    SchleswigHolstein,
+    Thuringia,
+}
+
+/// The public holidays of a country or a German state.
+///
+/// Holidays observed only in parts of a state, like Assumption in Bavaria, are left out.
+#[derive(Debug, Copy, Clone, Eq, PartialEq, Hash)]
+pub enum HolidayCalendar {
+    /// Germany, the nationwide holidays only with `None`.
+    De(Option<GermanState>),
+    /// Austria
+    At,
+    /// Switzerland, the holidays observed in most cantons.
+    Ch,
+    /// The federal holidays of the United States. One on a Saturday is observed on the
+    /// Friday before, one on a Sunday on the Monday after.
+    Us,
+    /// The bank holidays of England and Wales. One on a weekend is substituted by the next
+    /// working day.
+    Uk,
+}
+
+impl HolidayCalendar {
+    /// Whether `holiday` is a public holiday in `year` of this calendar.
+    pub fn observes(self, holiday: Holiday, year: i32) -> bool {
+        match self {
+            De(state) => {
+                let is = |states: &[GermanState]| state.is_some_and(|s| states.contains(&s));
+                match holiday {
+                    NewYear | GoodFriday | EasterMonday | WorkersDay | Ascension | WhitMonday
+                    | GermanUnityDay | ChristmasDay | StStephensDay => true,
+                    Epiphany => is(&[BadenWuerttemberg, Bavaria, SaxonyAnhalt]),
+                    WomensDay => {
+                        year >= 2019 && is(&[Berlin])
+                            || year >= 2023 && is(&[MecklenburgVorpommern])
+                    }
+                    EasterSunday | WhitSunday => is(&[Brandenburg]),
+                    CorpusChristi => is(&[
+                        BadenWuerttemberg,
+                        Bavaria,
+                        Hesse,
+                        NorthRhineWestphalia,
+                        RhinelandPalatinate,
+                        Saarland,
+                    ]),
+                    Assumption => is(&[Saarland]),
+                    WorldChildrensDay => year >= 2019 && is(&[Thuringia]),
+                    ReformationDay => {
+                        year == 2017
+                            || is(&[
+                                Brandenburg,
+                                MecklenburgVorpommern,
+                                Saxony,
+                                SaxonyAnhalt,
+                                Thuringia,
+                            ])
+                            || year >= 2018
+                                && is(&[Bremen, Hamburg, LowerSaxony, SchleswigHolstein])
+                    }
+                    AllSaints => is(&[
+                        BadenWuerttemberg,
+                        Bavaria,
+                        NorthRhineWestphalia,
+                        RhinelandPalatinate,
+                        Saarland,
+                    ]),
+                    RepentanceDay => is(&[Saxony]),
+                    _ => false,
+                }
+            }
+            At => matches!(
+                holiday,
+                NewYear
+                    | Epiphany
+                    | EasterMonday
+                    | WorkersDay
+                    | Ascension
+                    | WhitMonday
+                    | CorpusChristi
+                    | Assumption
+                    | AustrianNationalDay
+                    | AllSaints
+                    | ImmaculateConception
+                    | ChristmasDay
+                    | StStephensDay
+            ),
+            Ch => matches!(
+                holiday,
+                NewYear
+                    | GoodFriday
+                    | EasterMonday
+                    | Ascension
+                    | WhitMonday
+                    | SwissNationalDay
+                    | ChristmasDay
+                    | StStephensDay
+            ),
+            Us => match holiday {
+                NewYear | MartinLutherKingDay | PresidentsDay | MemorialDay | IndependenceDay
+                | LaborDay | ColumbusDay | VeteransDay | Thanksgiving | ChristmasDay => true,
+                Juneteenth => year >= 2021,
+                _ => false,
+            },
+            Uk => matches!(
+                holiday,
+                NewYear
+                    | GoodFriday
+                    | EasterMonday
+                    | EarlyMayBankHoliday
+                    | SpringBankHoliday
+                    | SummerBankHoliday
+                    | ChristmasDay
+                    | StStephensDay
+            ),
+        }
+    }
+
+    /// The holidays of `year` in calendar order, on the day they are observed. In the US, that
+    /// may be the 31st of December of the year before.
+    pub fn holidays(self, year: i32) -> Vec<(Holiday, Doy)> {
+        let mut holidays: Vec<(Holiday, Doy)> = Holiday::ALL
+            .iter()
+            .filter(|holiday| self.observes(**holiday, year))
+            .map(|holiday| (*holiday, holiday.date(year)))
+            .collect();
+        holidays.sort_by_key(|(_, date)| *date);
+        match self {
+            Us => {
+                for (_, date) in &mut holidays {
+                    match date.day_of_week() {
+                        Sat => *date = *date - 1,
+                        Sun => *date = *date + 1,
+                        _ => {}
+                    }
+                }
+            }
+            Uk => {
+                let mut taken: Vec<Doy> = holidays
+                    .iter()
+                    .map(|(_, date)| *date)
+                    .filter(|date| !is_weekend(*date))
+                    .collect();
+                for (_, date) in &mut holidays {
+                    if is_weekend(*date) {
+                        while is_weekend(*date) || taken.contains(date) {
+                            *date = *date + 1;
+                        }
+                        taken.push(*date);
+                    }
+                }
+            }
+            _ => {}
+        }
+        holidays
+    }
+
+    /// The amount of days from `start` (included) to `end` (excluded) observed as a holiday and
+    /// not on a weekend.
+    pub(crate) fn weekday_holidays(self, start: Doy, end: Doy) -> i64 {
+        let mut count = 0;
+        // holidays observed on the same day, also across the turn of the year, count once
+        let mut previous = Vec::new();
+        for year in start.year..=end.year + 1 {
+            let mut dates: Vec<Doy> = self
+                .holidays(year)
+                .into_iter()
+                .map(|(_, date)| date)
+                .filter(|date| (start..end).contains(date) && !is_weekend(*date))
+                .collect();
+            dates.sort();
+            dates.dedup();
+            count += dates
+                .iter()
+                .filter(|date| !previous.contains(*date))
+                .count() as i64;
+            previous = dates;
+        }
+        count
+    }
+
+    /// The holiday observed on `date`, if any.
+    pub fn holiday(self, date: Doy) -> Option<Holiday> {
+        (date.year - 1..=date.year + 1)
+            .flat_map(|year| self.holidays(year))
+            .find(|(_, observed)| *observed == date)
+            .map(|(holiday, _)| holiday)
+    }
+}
+
+/// Easter Sunday of `year` in the Gregorian calendar (anonymous Gregorian algorithm).
+fn easter_sunday(year: i32) -> Doy {
+    let a = year.rem_euclid(19);
+    let (b, c) = (year.div_euclid(100), year.rem_euclid(100));
+    let (d, e) = (b / 4, b % 4);
+    let f = (b + 8) / 25;
+    let g = (b - f + 1) / 3;
+    let h = (19 * a + b - d - g + 15) % 30;
+    let (i, k) = (c / 4, c % 4);
+    let l = (32 + 2 * e + 2 * i - h - k) % 7;
+    let m = (a + 11 * h + 22 * l) / 451;
+    let month = (h + l - 7 * m + 114) / 31;
+    let day = (h + l - 7 * m + 114) % 31 + 1;
+    Doy::from_ymd(year, month, day)
+}
+
+/// The `n`-th `weekday` of `month` in `year`.
+fn nth_weekday(year: i32, month: i32, n: i32, weekday: DayOfWeek) -> Doy {
+    let first = Doy::from_ymd(year, month, 1);
+    first + weekday.days_before(first.day_of_week()) % 7 + (n - 1) * 7
+}
+
+/// The last `weekday` of `month` in `year`.
+fn last_weekday(year: i32, month: i32, weekday: DayOfWeek) -> Doy {
+    let last = Doy::from_ymd(year, month, Doy::days_in_month(year, month));
+    last - last.day_of_week().days_before(weekday) % 7
+}
+
+fn is_weekend(date: Doy) -> bool {
+    matches!(date.day_of_week(), Sat | Sun)
+}
+
+#[cfg(test)]
+mod should {
+    use crate::holiday::{easter_sunday, GermanState, Holiday, HolidayCalendar};
+    use crate::Doy;
+
+    #[test]
+    fn compute_easter() {
+        assert_eq!(Doy::from_ymd(2023, 4, 9), easter_sunday(2023));
+        assert_eq!(Doy::from_ymd(2024, 3, 31), easter_sunday(2024));
+        assert_eq!(Doy::from_ymd(2025, 4, 20), easter_sunday(2025));
+        assert_eq!(Doy::from_ymd(2038, 4, 25), easter_sunday(2038));
+        assert_eq!(Doy::from_ymd(2285, 3, 22), easter_sunday(2285));
+        assert_eq!(Doy::from_ymd(1961, 4, 2), easter_sunday(1961));
+    }
+
+    #[test]
+    fn compute_movable_feasts() {
+        assert_eq!(Doy::from_ymd(2024, 3, 29), Holiday::GoodFriday.date(2024));
+        assert_eq!(Doy::from_ymd(2024, 5, 9), Holiday::Ascension.date(2024));
+        assert_eq!(Doy::from_ymd(2024, 5, 20), Holiday::WhitMonday.date(2024));
+        assert_eq!(
+            Doy::from_ymd(2024, 5, 30),
+            Holiday::CorpusChristi.date(2024)
+        );
+        assert_eq!(
+            Doy::from_ymd(2023, 11, 22),
+            Holiday::RepentanceDay.date(2023)
+        );
+        assert_eq!(
+            Doy::from_ymd(2024, 11, 20),
+            Holiday::RepentanceDay.date(2024)
+        );
+        assert_eq!(
+            Doy::from_ymd(2028, 11, 22),
+            Holiday::RepentanceDay.date(2028)
+        );
+        assert_eq!(
+            Doy::from_ymd(2023, 11, 23),
+            Holiday::Thanksgiving.date(2023)
+        );
+        assert_eq!(Doy::from_ymd(2023, 5, 29), Holiday::MemorialDay.date(2023));
+        assert_eq!(
+            Doy::from_ymd(2023, 1, 16),
+            Holiday::MartinLutherKingDay.date(2023)
+        );
+        assert_eq!(
+            Doy::from_ymd(2023, 8, 28),
+            Holiday::SummerBankHoliday.date(2023)
+        );
+    }
+
+    #[test]
+    fn list_holidays_by_state() {
+        let count = |calendar: HolidayCalendar| calendar.holidays(2024).len();
+        assert_eq!(9, count(HolidayCalendar::De(None)));
+        assert_eq!(12, count(HolidayCalendar::De(Some(GermanState::Bavaria))));
+        assert_eq!(10, count(HolidayCalendar::De(Some(GermanState::Berlin))));
+        assert_eq!(10, count(HolidayCalendar::De(Some(GermanState::Hamburg))));
+        assert_eq!(13, count(HolidayCalendar::At));
+        assert_eq!(8, count(HolidayCalendar::Ch));
+        assert_eq!(11, count(HolidayCalendar::Us));
+        assert_eq!(8, count(HolidayCalendar::Uk));
+        let saxony = HolidayCalendar::De(Some(GermanState::Saxony));
+        assert!(Doy::from_ymd(2023, 11, 22).is_holiday(saxony));
+        assert!(!Doy::from_ymd(2023, 11, 22).is_holiday(HolidayCalendar::De(None)));
+        assert!(!HolidayCalendar::De(Some(GermanState::Hamburg))
+            .observes(Holiday::ReformationDay, 2016));
+    }
+
+    #[test]
+    fn observe_holidays_on_weekends() {
+        // Christmas 2021 is on Saturday
+        let us = HolidayCalendar::Us;
+        assert_eq!(
+            Some(Holiday::ChristmasDay),
+            us.holiday(Doy::from_ymd(2021, 12, 24))
+        );
+        assert_eq!(
+            Some(Holiday::NewYear),
+            us.holiday(Doy::from_ymd(2021, 12, 31))
+        );
+        assert!(!Doy::from_ymd(2021, 12, 25).is_holiday(us));
+        let uk = HolidayCalendar::Uk;
+        assert_eq!(
+            Some(Holiday::ChristmasDay),
+            uk.holiday(Doy::from_ymd(2021, 12, 27))
+        );
+        assert_eq!(
+            Some(Holiday::StStephensDay),
+            uk.holiday(Doy::from_ymd(2021, 12, 28))
+        );
+        // Christmas 2022 is on Sunday
+        assert_eq!(
+            Some(Holiday::StStephensDay),
+            uk.holiday(Doy::from_ymd(2022, 12, 26))
+        );
+        assert_eq!(
+            Some(Holiday::ChristmasDay),
+            uk.holiday(Doy::from_ymd(2022, 12, 27))
+        );
+        let de = HolidayCalendar::De(None);
+        assert!(Doy::from_ymd(2022, 12, 25).is_holiday(de));
+        assert!(!Doy::from_ymd(2022, 12, 27).is_holiday(de));
+    }
+}
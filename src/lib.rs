@@ -71,18 +71,50 @@
 //!     Tempus::Moment(Doy::from_ymd(2023, 3, 6))
 //! );
 //! ```
+//!
+//! Times of day are resolved into a `Tempus::Instant`
+//! ```rust
+//! use timewarp::Direction::*;
+//! use timewarp::{date_matcher, DateTime, Doy, Tempus, TimeOfDay};
+//!
+//! // Fri 2023-03-17
+//! let today = Doy::from_ymd(2023, 3, 17);
+//! assert_eq!(
+//!     date_matcher(today, From, "tomorrow 14:30").unwrap(),
+//!     Tempus::Instant(DateTime::new(Doy::from_ymd(2023, 3, 18), TimeOfDay::new(14, 30)))
+//! );
+//! assert_eq!(
+//!     date_matcher(today, From, "next monday at 3pm").unwrap(),
+//!     Tempus::Instant(DateTime::new(Doy::from_ymd(2023, 3, 20), TimeOfDay::new(15, 0)))
+//! );
+//! assert_eq!(
+//!     date_matcher(today, From, "heute Mittag").unwrap(),
+//!     Tempus::Instant(DateTime::new(today, TimeOfDay::NOON))
+//! );
+//! ```
 
 #[macro_use]
 extern crate pest_derive;
 
 mod date_matcher;
+mod date_parser;
+mod date_time;
 mod day_of_week;
 mod doy;
 mod error;
+mod holiday;
 mod month_of_year;
+mod number_words;
+mod period;
+mod time_of_day;
 
-pub use date_matcher::{date_matcher, Direction};
+pub use date_matcher::{date_matcher, date_matcher_strict, Direction};
+pub use date_parser::{DateParser, DateParserBuilder, Locale, YearInference};
+pub use date_time::DateTime;
 pub use day_of_week::DayOfWeek;
-pub use doy::{Doy, Tempus};
-pub use error::TimeWarpError;
+pub use doy::{Doy, EndOfMonth, Tempus};
+pub use error::{ErrorKind, TimeWarpError};
+pub use holiday::{GermanState, Holiday, HolidayCalendar};
 pub use month_of_year::Month;
+pub use period::Period;
+pub use time_of_day::{DayPart, TimeOfDay};
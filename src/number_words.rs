@@ -0,0 +1,189 @@
+//! Spelled-out numbers and ordinals in English and German, like "twenty-one",
+//! "einundzwanzig", "third" or "dritten".
+
+const EN_UNITS: [&str; 20] = [
+    "zero",
+    "one",
+    "two",
+    "three",
+    "four",
+    "five",
+    "six",
+    "seven",
+    "eight",
+    "nine",
+    "ten",
+    "eleven",
+    "twelve",
+    "thirteen",
+    "fourteen",
+    "fifteen",
+    "sixteen",
+    "seventeen",
+    "eighteen",
+    "nineteen",
+];
+const EN_TENS: [&str; 10] = [
+    "", "", "twenty", "thirty", "forty", "fifty", "sixty", "seventy", "eighty", "ninety",
+];
+const DE_UNITS: [&str; 20] = [
+    "null",
+    "eins",
+    "zwei",
+    "drei",
+    "vier",
+    "fünf",
+    "sechs",
+    "sieben",
+    "acht",
+    "neun",
+    "zehn",
+    "elf",
+    "zwölf",
+    "dreizehn",
+    "vierzehn",
+    "fünfzehn",
+    "sechzehn",
+    "siebzehn",
+    "achtzehn",
+    "neunzehn",
+];
+const DE_TENS: [&str; 10] = [
+    "", "", "zwanzig", "dreißig", "vierzig", "fünfzig", "sechzig", "siebzig", "achtzig", "neunzig",
+];
+
+/// The value of a cardinal number word from "zero" to "ninety-nine" or from "null" to
+/// "neunundneunzig". Articles like "a", "an" or "einer" count as one.
+pub(crate) fn number(word: &str) -> Option<i32> {
+    let word = word.trim().to_lowercase().replace("ss", "ß");
+    match word.as_str() {
+        "a" | "an" | "ein" | "eine" | "einer" | "einem" | "einen" => return Some(1),
+        _ => {}
+    }
+    if let Some((tens, unit)) = word.split_once(['-', ' ']) {
+        let unit = position(&EN_UNITS, unit.trim()).filter(|unit| (1..10).contains(unit))?;
+        return Some(position(&EN_TENS, tens)? * 10 + unit);
+    }
+    if let Some((unit, tens)) = word.split_once("und") {
+        let unit = if unit == "ein" {
+            1
+        } else {
+            position(&DE_UNITS, unit).filter(|unit| (2..10).contains(unit))?
+        };
+        return Some(position(&DE_TENS, tens)? * 10 + unit);
+    }
+    position(&EN_UNITS, &word)
+        .or_else(|| position(&DE_UNITS, &word))
+        .or_else(|| position(&EN_TENS, &word).map(|tens| tens * 10))
+        .or_else(|| position(&DE_TENS, &word).map(|tens| tens * 10))
+}
+
+/// The value of an ordinal number word from "first" to "ninety-ninth" or from "erste" to
+/// "neunundneunzigste", declined forms like "dritten" included.
+pub(crate) fn ordinal(word: &str) -> Option<i32> {
+    let word = word.trim().to_lowercase();
+    ordinal_en(&word).or_else(|| ordinal_de(&word))
+}
+
+fn ordinal_en(word: &str) -> Option<i32> {
+    let (tens, last) = match word.rsplit_once(['-', ' ']) {
+        Some((tens, last)) => (position(&EN_TENS, tens)? * 10, last),
+        None => (0, word),
+    };
+    let unit = match last {
+        "first" => 1,
+        "second" => 2,
+        "third" => 3,
+        "fifth" => 5,
+        "eighth" => 8,
+        "ninth" => 9,
+        "twelfth" => 12,
+        _ => {
+            let stem = last.strip_suffix("th")?;
+            match stem.strip_suffix("ie") {
+                Some(tens) => number(&format!("{tens}y"))?,
+                None => number(stem)?,
+            }
+        }
+    };
+    if tens > 0 && !(1..10).contains(&unit) {
+        return None;
+    }
+    Some(tens + unit)
+}
+
+fn ordinal_de(word: &str) -> Option<i32> {
+    // declined forms: dritter, dritten, drittes, drittem
+    let word = match word.strip_suffix(['r', 'n', 's', 'm']) {
+        Some(stem) if stem.ends_with('e') => stem,
+        _ => word,
+    };
+    match word {
+        "erste" => Some(1),
+        "dritte" => Some(3),
+        "siebte" => Some(7),
+        "achte" => Some(8),
+        _ => word
+            .strip_suffix("ste")
+            .and_then(number)
+            .filter(|n| *n >= 20)
+            .or_else(|| word.strip_suffix("te").and_then(number))
+            .filter(|n| *n > 0),
+    }
+}
+
+fn position(words: &[&str], word: &str) -> Option<i32> {
+    words
+        .iter()
+        .position(|w| !w.is_empty() && *w == word)
+        .map(|i| i as i32)
+}
+
+#[cfg(test)]
+mod should {
+    use crate::number_words::{number, ordinal};
+
+    #[test]
+    fn parse_numbers() {
+        assert_eq!(Some(3), number("three"));
+        assert_eq!(Some(3), number("Drei"));
+        assert_eq!(Some(14), number("vierzehn"));
+        assert_eq!(Some(14), number("fourteen"));
+        assert_eq!(Some(21), number("twenty-one"));
+        assert_eq!(Some(21), number("twenty one"));
+        assert_eq!(Some(21), number("einundzwanzig"));
+        assert_eq!(Some(34), number("vierunddreissig"));
+        assert_eq!(Some(40), number("forty"));
+        assert_eq!(Some(1), number("einer"));
+        assert_eq!(Some(1), number("an"));
+        assert_eq!(Some(0), number("null"));
+        assert_eq!(None, number("twenty-eleven"));
+        assert_eq!(None, number("dreiundvierzehn"));
+        assert_eq!(None, number("lots"));
+    }
+
+    #[test]
+    fn parse_ordinals() {
+        assert_eq!(Some(1), ordinal("first"));
+        assert_eq!(Some(3), ordinal("third"));
+        assert_eq!(Some(4), ordinal("fourth"));
+        assert_eq!(Some(12), ordinal("twelfth"));
+        assert_eq!(Some(13), ordinal("thirteenth"));
+        assert_eq!(Some(20), ordinal("twentieth"));
+        assert_eq!(Some(21), ordinal("twenty-first"));
+        assert_eq!(Some(31), ordinal("Thirty-First"));
+        assert_eq!(Some(1), ordinal("erster"));
+        assert_eq!(Some(3), ordinal("dritte"));
+        assert_eq!(Some(3), ordinal("dritten"));
+        assert_eq!(Some(6), ordinal("sechste"));
+        assert_eq!(Some(7), ordinal("siebten"));
+        assert_eq!(Some(8), ordinal("achter"));
+        assert_eq!(Some(19), ordinal("neunzehnte"));
+        assert_eq!(Some(20), ordinal("zwanzigsten"));
+        assert_eq!(Some(21), ordinal("einundzwanzigste"));
+        assert_eq!(Some(30), ordinal("dreißigste"));
+        assert_eq!(None, ordinal("twenty-twelfth"));
+        assert_eq!(None, ordinal("nullte"));
+        assert_eq!(None, ordinal("month"));
+    }
+}
@@ -0,0 +1,119 @@
+use crate::doy::Doy;
+use std::fmt::{Display, Formatter};
+
+/// A calendar-aware amount of time: years, months and days.
+#[derive(Eq, PartialEq, Copy, Clone, Debug, Default)]
+pub struct Period {
+    pub years: i32,
+    pub months: i32,
+    pub days: i32,
+}
+
+impl Period {
+    /// The calendar difference from `start` to `end`. Whole months are counted first, the
+    /// remaining days after. If `end` is before `start` all components are negative.
+    pub fn between(start: Doy, end: Doy) -> Self {
+        if end < start {
+            let Self {
+                years,
+                months,
+                days,
+            } = Self::between(end, start);
+            return Self {
+                years: -years,
+                months: -months,
+                days: -days,
+            };
+        }
+        let (sm, sd) = (start.month() as i32, start.day_of_month());
+        let (em, ed) = (end.month() as i32, end.day_of_month());
+        let mut total_months = (end.year - start.year) * 12 + em - sm;
+        if ed < sd {
+            total_months -= 1;
+        }
+        let months = start.year * 12 + sm - 1 + total_months;
+        let (year, month) = (months.div_euclid(12), months.rem_euclid(12) + 1);
+        let anchor = Doy::from_ymd(year, month, sd.min(Doy::days_in_month(year, month)));
+        Self {
+            years: total_months / 12,
+            months: total_months % 12,
+            days: (end - anchor) as i32,
+        }
+    }
+
+    /// The amount of whole months, years included.
+    pub fn total_months(self) -> i32 {
+        self.years * 12 + self.months
+    }
+}
+
+impl Display for Period {
+    fn fmt(&self, f: &mut Formatter<'_>) -> Result<(), std::fmt::Error> {
+        let parts: Vec<String> = [
+            (self.years, "year"),
+            (self.months, "month"),
+            (self.days, "day"),
+        ]
+        .iter()
+        .filter(|(amount, _)| *amount != 0)
+        .map(|(amount, unit)| {
+            let s = if amount.abs() == 1 { "" } else { "s" };
+            format!("{amount} {unit}{s}")
+        })
+        .collect();
+        if parts.is_empty() {
+            write!(f, "0 days")
+        } else {
+            write!(f, "{}", parts.join(", "))
+        }
+    }
+}
+
+#[cfg(test)]
+mod should {
+    use crate::doy::Doy;
+    use crate::period::Period;
+
+    fn period(years: i32, months: i32, days: i32) -> Period {
+        Period {
+            years,
+            months,
+            days,
+        }
+    }
+
+    #[test]
+    fn count_years_months_days() {
+        let start = Doy::from_ymd(2022, 1, 14);
+        assert_eq!(period(1, 2, 3), start.until(Doy::from_ymd(2023, 3, 17)));
+        assert_eq!(period(0, 0, 0), start.until(start));
+        assert_eq!(period(0, 11, 30), start.until(Doy::from_ymd(2023, 1, 13)));
+        assert_eq!(period(-1, -2, -3), Doy::from_ymd(2023, 3, 17).until(start));
+    }
+
+    #[test]
+    fn handle_end_of_month() {
+        let jan_31 = Doy::from_ymd(2023, 1, 31);
+        assert_eq!(period(0, 0, 28), jan_31.until(Doy::from_ymd(2023, 2, 28)));
+        assert_eq!(period(0, 2, 0), jan_31.until(Doy::from_ymd(2023, 3, 31)));
+        assert_eq!(period(0, 2, 1), jan_31.until(Doy::from_ymd(2023, 4, 1)));
+        let leap_day = Doy::from_ymd(2020, 2, 29);
+        assert_eq!(
+            period(0, 11, 30),
+            leap_day.until(Doy::from_ymd(2021, 2, 28))
+        );
+        assert_eq!(period(4, 0, 0), leap_day.until(Doy::from_ymd(2024, 2, 29)));
+        assert_eq!(
+            47,
+            leap_day.until(Doy::from_ymd(2024, 2, 28)).total_months()
+        );
+    }
+
+    #[test]
+    fn convert_to_string() {
+        assert_eq!("1 year, 2 months, 3 days", period(1, 2, 3).to_string());
+        assert_eq!("1 month, 1 day", period(0, 1, 1).to_string());
+        assert_eq!("-2 years", period(-2, 0, 0).to_string());
+        assert_eq!("0 days", Period::default().to_string());
+    }
+}
@@ -0,0 +1,180 @@
+use self::DayPart::{Afternoon, Evening, Midnight, Morning, Night, Noon, Unknown};
+use crate::date_matcher::Rule;
+use crate::date_time::DateTime;
+use crate::doy::Doy;
+use std::fmt::{Display, Formatter};
+
+/// Time of day, precise to the second.
+#[must_use]
+#[derive(Eq, PartialEq, Ord, PartialOrd, Hash, Copy, Clone, Debug, Default)]
+pub struct TimeOfDay {
+    pub hour: i32,
+    pub minute: i32,
+    pub second: i32,
+}
+
+impl TimeOfDay {
+    pub const MIDNIGHT: Self = Self {
+        hour: 0,
+        minute: 0,
+        second: 0,
+    };
+    pub const NOON: Self = Self {
+        hour: 12,
+        minute: 0,
+        second: 0,
+    };
+
+    /// Creates a `TimeOfDay` from `hour` and `minute`.
+    ///
+    /// # Panics
+    /// panics if `hour` is not in 0..23 or `minute` is not in 0..59
+    pub fn new(hour: i32, minute: i32) -> Self {
+        Self::hms(hour, minute, 0)
+    }
+
+    /// Creates a `TimeOfDay` from `hour`, `minute` and `second`.
+    ///
+    /// # Panics
+    /// panics if `hour` is not in 0..23, `minute` or `second` not in 0..59
+    pub fn hms(hour: i32, minute: i32, second: i32) -> Self {
+        assert!((0..24).contains(&hour), "Hour has to be in 0..23");
+        assert!((0..60).contains(&minute), "Minute has to be in 0..59");
+        assert!((0..60).contains(&second), "Second has to be in 0..59");
+        Self {
+            hour,
+            minute,
+            second,
+        }
+    }
+
+    /// Creates a `TimeOfDay` from the seconds since midnight, wrapping around at 24h.
+    pub fn from_seconds(seconds: i32) -> Self {
+        let seconds = seconds.rem_euclid(24 * 3600);
+        Self::hms(seconds / 3600, seconds / 60 % 60, seconds % 60)
+    }
+
+    /// Seconds since midnight.
+    pub fn seconds(self) -> i32 {
+        self.hour * 3600 + self.minute * 60 + self.second
+    }
+}
+
+impl Display for TimeOfDay {
+    fn fmt(&self, f: &mut Formatter<'_>) -> Result<(), std::fmt::Error> {
+        let Self {
+            hour,
+            minute,
+            second,
+        } = self;
+        if f.alternate() {
+            write!(f, "{hour:02}:{minute:02}:{second:02}")
+        } else {
+            write!(f, "{hour:02}{minute:02}{second:02}")
+        }
+    }
+}
+
+/// Parts of the day - as used in "tomorrow morning" or "heute Abend".
+#[derive(Debug, Default, Copy, Clone, Eq, PartialEq)]
+pub enum DayPart {
+    Morning,
+    Noon,
+    Afternoon,
+    Evening,
+    Night,
+    Midnight,
+    #[default]
+    Unknown,
+}
+
+impl DayPart {
+    pub(crate) fn from_day_part(rule: Rule) -> Self {
+        match rule {
+            Rule::morning => Morning,
+            Rule::noon => Noon,
+            Rule::afternoon => Afternoon,
+            Rule::evening => Evening,
+            Rule::night => Night,
+            Rule::midnight => Midnight,
+            _ => Unknown,
+        }
+    }
+
+    /// The hours this part of the day spans (end exclusive, may exceed 24).
+    fn hours(self) -> (i32, i32) {
+        match self {
+            Morning => (6, 12),
+            Noon | Unknown => (12, 12),
+            Afternoon => (12, 18),
+            Evening => (18, 22),
+            Night => (22, 30),
+            // the end of the day
+            Midnight => (24, 24),
+        }
+    }
+
+    /// The beginning of this part of the `date`.
+    pub fn start(self, date: Doy) -> DateTime {
+        DateTime::new(date, TimeOfDay::default()).add_seconds(self.hours().0 * 3600)
+    }
+
+    /// The end of this part of the `date`. The night ends on the following morning.
+    pub fn end(self, date: Doy) -> DateTime {
+        DateTime::new(date, TimeOfDay::default()).add_seconds(self.hours().1 * 3600)
+    }
+
+    /// Moves a 12h-clock `time` into this part of the day: "8 Uhr abends" is 20:00.
+    pub fn adjust(self, time: TimeOfDay) -> TimeOfDay {
+        let pm = match self {
+            Afternoon | Evening => time.hour < 12,
+            Noon => time.hour < 6,
+            Night => (6..12).contains(&time.hour),
+            Morning | Midnight | Unknown => false,
+        };
+        if pm {
+            TimeOfDay::hms(time.hour + 12, time.minute, time.second)
+        } else {
+            time
+        }
+    }
+}
+
+#[cfg(test)]
+mod should {
+    use super::DayPart::{Evening, Night, Noon};
+    use super::TimeOfDay;
+    use crate::date_time::DateTime;
+    use crate::doy::Doy;
+
+    #[test]
+    fn convert_to_string() {
+        assert_eq!("143005", TimeOfDay::hms(14, 30, 5).to_string());
+        assert_eq!("09:05:00", format!("{:#}", TimeOfDay::new(9, 5)));
+    }
+
+    #[test]
+    fn wrap_seconds() {
+        assert_eq!(TimeOfDay::new(23, 30), TimeOfDay::from_seconds(-1800));
+        assert_eq!(TimeOfDay::hms(0, 0, 1), TimeOfDay::from_seconds(86401));
+        assert_eq!(52200, TimeOfDay::new(14, 30).seconds());
+    }
+
+    #[test]
+    fn span_day_parts() {
+        let day = Doy::from_ymd(2023, 3, 17);
+        assert_eq!(
+            DateTime::new(day, TimeOfDay::new(18, 0)),
+            Evening.start(day)
+        );
+        assert_eq!(DateTime::new(day + 1, TimeOfDay::new(6, 0)), Night.end(day));
+        assert_eq!(Noon.start(day), Noon.end(day));
+    }
+
+    #[test]
+    fn adjust_to_day_part() {
+        assert_eq!(TimeOfDay::new(20, 0), Evening.adjust(TimeOfDay::new(8, 0)));
+        assert_eq!(TimeOfDay::new(2, 0), Night.adjust(TimeOfDay::new(2, 0)));
+        assert_eq!(TimeOfDay::new(13, 0), Noon.adjust(TimeOfDay::new(1, 0)));
+    }
+}